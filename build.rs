@@ -0,0 +1,56 @@
+//! Expose the syscalls of the selected SP1 release to the crate as `sp1_syscall = "NAME"` cfgs,
+//! one per entry of its pinned table under `syscalls/`.
+//!
+//! Wrappers are gated on these cfgs, so a syscall missing from the selected release has no
+//! wrapper.
+
+use std::{env, fs, path::Path};
+
+const VERSIONS: [&str; 3] = ["sp1-v1", "sp1-v2", "sp1-v3"];
+
+/// Syscalls with wrappers in the crate that no pinned table lists, so the wrappers are never
+/// compiled.
+const UNPINNED: [&str; 1] = ["BN254_MULADD"];
+
+/// The names of the `NAME = 0x..` entries of a pinned table.
+fn names(table: &str) -> impl Iterator<Item = &str> {
+    table.lines().filter_map(|line| {
+        let (name, code) = line.split_once(" = ")?;
+        code.starts_with("0x").then_some(name)
+    })
+}
+
+fn main() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("syscalls");
+    println!("cargo::rerun-if-changed=syscalls");
+
+    let tables = VERSIONS.map(|version| {
+        let path = dir.join(format!("{version}.toml"));
+        let table = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()));
+        (version, table)
+    });
+
+    let mut all: Vec<&str> = tables.iter().flat_map(|(_, table)| names(table)).collect();
+    all.extend(UNPINNED);
+    all.sort_unstable();
+    all.dedup();
+    let values: Vec<String> = all.iter().map(|name| format!("\"{name}\"")).collect();
+    println!(
+        "cargo::rustc-check-cfg=cfg(sp1_syscall, values({}))",
+        values.join(", ")
+    );
+
+    // Like `SP1_TABLE` in `src/syscall.rs`, fall back to the last release, so that a wrong
+    // selection only reports the `compile_error!`s of `src/lib.rs`.
+    let (_, table) = tables
+        .iter()
+        .find(|(version, _)| {
+            let feature = version.to_uppercase().replace('-', "_");
+            env::var_os(format!("CARGO_FEATURE_{feature}")).is_some()
+        })
+        .unwrap_or(&tables[2]);
+    for name in names(table) {
+        println!("cargo::rustc-cfg=sp1_syscall=\"{name}\"");
+    }
+}
@@ -0,0 +1,305 @@
+//! The `#[sp1_syscall]` attribute, re-exported by `sp1-intrinsics` behind its `macros` feature.
+//!
+//! See [`macro@sp1_syscall`].
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, Attribute, Expr, ForeignItemFn, Ident, Path, Token, Type};
+
+/// Declare a syscall from a single specification.
+///
+/// The attribute goes on a bodiless function named after the safe wrapper, and carries the
+/// syscall ID, its pointer arguments in register order and, optionally, a host implementation:
+///
+/// ```ignore
+/// /// Perform in-place scalar multiplication `p *= q`.
+/// #[sp1_syscall(id = 0x00_01_01_80, args(p: mut Fr, q: Fr))]
+/// pub fn bn254_scalar_mul();
+/// ```
+///
+/// This expands to
+///
+/// * the ID constant `BN254_SCALAR_MUL`;
+/// * `unsafe fn syscall_bn254_scalar_mul(p: *mut Fr, q: *const Fr)`, which issues the syscall,
+///   with a `# Safety` section derived from the arguments;
+/// * `fn bn254_scalar_mul(p: &mut Fr, q: &Fr)`, which borrows its operands and is therefore
+///   safe.
+///
+/// An argument marked `mut` is read and written, any other argument is only read. The
+/// generated contract forbids a mutable argument to overlap any other argument, which the
+/// borrows of the safe wrapper guarantee; read-only arguments may overlap each other. A syscall
+/// with a single argument is issued with `0` as its second argument, like the wrappers of this
+/// crate do.
+///
+/// The safe wrapper is only sound if every value of the operand types is an input the
+/// syscall can prove, as every `bn254::Fr` is for the bn254 scalar syscalls. Do not declare a
+/// syscall whose inputs are restricted further over a plain type, such as a curve addition with
+/// `args(p: mut [u32; 16], q: [u32; 16])`: its safe wrapper would be a safe way to emit an
+/// `ecall` that cannot be proven. Write the wrappers of such a syscall by hand instead.
+///
+/// With the `checked` feature of `sp1-intrinsics`, the unsafe wrapper checks at runtime that
+/// every argument is non-null and aligned, and that no mutable argument overlaps another one.
+///
+/// With `host = path::to::function`, the wrapper calls that function with the raw pointers
+/// instead of issuing the syscall when not building for the zkVM. Without it, the syscall goes
+/// to the host emulation backend of `sp1-intrinsics`, which only knows its own syscalls.
+#[proc_macro_attribute]
+pub fn sp1_syscall(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let spec = syn::parse_macro_input!(attr as Spec);
+    let item = syn::parse_macro_input!(item as ForeignItemFn);
+    expand(spec, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The arguments of the attribute.
+struct Spec {
+    id: Expr,
+    args: Vec<Arg>,
+    host: Option<Path>,
+}
+
+/// A pointer argument of the syscall.
+struct Arg {
+    name: Ident,
+    mutable: bool,
+    ty: Type,
+}
+
+impl Parse for Spec {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (mut id, mut args, mut host) = (None, None, None);
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            match key.to_string().as_str() {
+                "id" if id.is_none() => {
+                    input.parse::<Token![=]>()?;
+                    id = Some(input.parse()?);
+                }
+                "args" if args.is_none() => {
+                    let content;
+                    parenthesized!(content in input);
+                    let list = Punctuated::<Arg, Token![,]>::parse_terminated(&content)?;
+                    args = Some(list.into_iter().collect());
+                }
+                "host" if host.is_none() => {
+                    input.parse::<Token![=]>()?;
+                    host = Some(input.parse()?);
+                }
+                "id" | "args" | "host" => return Err(syn::Error::new(key.span(), "duplicate key")),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "expected `id`, `args` or `host`",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let id = id.ok_or_else(|| input.error("missing `id = ...`"))?;
+        let args: Vec<Arg> = args.ok_or_else(|| input.error("missing `args(...)`"))?;
+        if args.is_empty() || args.len() > 4 {
+            return Err(input.error("a syscall takes one to four pointer arguments"));
+        }
+        Ok(Self { id, args, host })
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let mutable = input.parse::<Option<Token![mut]>>()?.is_some();
+        let ty = input.parse()?;
+        Ok(Self { name, mutable, ty })
+    }
+}
+
+fn expand(spec: Spec, item: ForeignItemFn) -> syn::Result<TokenStream2> {
+    let sig = &item.sig;
+    if !sig.inputs.is_empty() || !matches!(sig.output, syn::ReturnType::Default) {
+        return Err(syn::Error::new_spanned(
+            sig,
+            "the arguments go in `args(...)` and a syscall returns nothing",
+        ));
+    }
+    if sig.unsafety.is_some() || !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            sig,
+            "expected a plain `fn name();` declaration",
+        ));
+    }
+
+    let vis = &item.vis;
+    let docs: Vec<&Attribute> = item
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .collect();
+    let safe_name = &sig.ident;
+    let unsafe_name = format_ident!("syscall_{}", safe_name);
+    let id_name = Ident::new(&safe_name.to_string().to_uppercase(), safe_name.span());
+    let id_doc = format!("`{id_name}` syscall ID.");
+    let id = &spec.id;
+
+    let names: Vec<&Ident> = spec.args.iter().map(|arg| &arg.name).collect();
+    let pointers = spec.args.iter().map(|arg| {
+        let (name, ty) = (&arg.name, &arg.ty);
+        if arg.mutable {
+            quote!(#name: *mut #ty)
+        } else {
+            quote!(#name: *const #ty)
+        }
+    });
+    let references = spec.args.iter().map(|arg| {
+        let (name, ty) = (&arg.name, &arg.ty);
+        if arg.mutable {
+            quote!(#name: &mut #ty)
+        } else {
+            quote!(#name: &#ty)
+        }
+    });
+    let casts = spec.args.iter().map(|arg| {
+        let (name, ty) = (&arg.name, &arg.ty);
+        if arg.mutable {
+            quote!(#name as *mut #ty)
+        } else {
+            quote!(#name as *const #ty)
+        }
+    });
+
+    // The `# Safety` contract the `checked` feature of `sp1-intrinsics` checks before the `ecall`.
+    let pointer_checks = names.iter().map(|name| {
+        let label = name.to_string();
+        quote!(::sp1_intrinsics::__checked::pointer(#label, #name);)
+    });
+    let mut disjoint_checks = Vec::new();
+    for (i, arg) in spec.args.iter().enumerate() {
+        for other in &spec.args[i + 1..] {
+            if arg.mutable || other.mutable {
+                let (a, b) = (&arg.name, &other.name);
+                let (a_label, b_label) = (a.to_string(), b.to_string());
+                disjoint_checks.push(quote! {
+                    ::sp1_intrinsics::__checked::disjoint((#a_label, #b_label), #a, #b);
+                });
+            }
+        }
+    }
+
+    // The syscall always gets at least `a0` and `a1`.
+    let zero = (spec.args.len() == 1).then(|| quote!(, 0));
+    let registers = quote!(#id_name, #(#names),* #zero);
+    let issue = match &spec.host {
+        Some(host) => quote! {
+            ::sp1_intrinsics::__syscall_with_host!(#host, [#(#names),*], #registers)
+        },
+        None => quote!(::sp1_intrinsics::syscall!(#registers; options(nostack))),
+    };
+
+    let safety = safety_doc(&spec.args);
+    let safe_doc = format!(
+        "The signature ensures {}, and that no mutable argument overlaps another argument, so \
+         this function is safe as long as every value of the argument types is a valid input \
+         of the syscall.",
+        access_doc(&spec.args)
+    );
+
+    Ok(quote! {
+        #[doc = #id_doc]
+        #vis const #id_name: u32 = #id;
+
+        #(#docs)*
+        #[doc = ""]
+        #(#[doc = #safety])*
+        #[inline(always)]
+        #vis unsafe fn #unsafe_name(#(#pointers),*) {
+            #(#pointer_checks)*
+            #(#disjoint_checks)*
+            unsafe { #issue }
+        }
+
+        #(#docs)*
+        #[doc = ""]
+        #[doc = #safe_doc]
+        #[inline(always)]
+        #vis fn #safe_name(#(#references),*) {
+            unsafe { #unsafe_name(#(#casts),*) }
+        }
+    })
+}
+
+/// The lines of the `# Safety` section, in the wording of the hand-written wrappers.
+fn safety_doc(args: &[Arg]) -> Vec<String> {
+    let mut lines = vec![
+        "# Safety".to_owned(),
+        String::new(),
+        "Behavior is undefined if any of the following conditions are violated:".to_owned(),
+        String::new(),
+    ];
+    for arg in args {
+        let ty = type_name(&arg.ty);
+        let access = if arg.mutable {
+            "reads and writes"
+        } else {
+            "reads"
+        };
+        lines.push(format!(
+            "* `{}` must be [valid] for {access} of `{ty}`.",
+            arg.name
+        ));
+        lines.push(String::new());
+    }
+    let names = list(args.iter().map(|arg| format!("`{}`", arg.name)));
+    lines.push(match args.len() {
+        1 => format!("* {names} must be properly aligned."),
+        2 => format!("* Both {names} must be properly aligned."),
+        _ => format!("* All of {names} must be properly aligned."),
+    });
+    lines.push(String::new());
+    // Read-only arguments may alias each other, as the references of the safe wrapper can.
+    for arg in args.iter().filter(|arg| arg.mutable) {
+        let others = args.iter().filter(|other| other.name != arg.name);
+        let others: Vec<String> = others.map(|other| format!("`{}`", other.name)).collect();
+        if !others.is_empty() {
+            let others = others.join(" or ");
+            lines.push(format!("* `{}` must not overlap {others}.", arg.name));
+            lines.push(String::new());
+        }
+    }
+    lines.push("[valid]: core::ptr#safety".to_owned());
+    lines
+}
+
+/// The accesses the references of the safe wrapper guarantee, e.g. "write access to `p` and
+/// read access to `q`".
+fn access_doc(args: &[Arg]) -> String {
+    list(args.iter().map(|arg| {
+        let access = if arg.mutable { "write" } else { "read" };
+        format!("{access} access to `{}`", arg.name)
+    }))
+}
+
+/// Join `items` as "a", "a and b" or "a, b and c".
+fn list(items: impl Iterator<Item = String>) -> String {
+    let items: Vec<String> = items.collect();
+    match items.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+/// The type as written, without the spaces `quote` puts between tokens.
+fn type_name(ty: &Type) -> String {
+    let name = quote!(#ty).to_string();
+    let name = name.replace(" ;", ";").replace(" ,", ",");
+    name.replace("[ ", "[")
+        .replace(" ]", "]")
+        .replace(" :: ", "::")
+}
@@ -0,0 +1,324 @@
+//! Fixed-width modular arithmetic over little-endian `u32` limbs.
+//!
+//! The limb layout matches the one used by the precompiles, so values can be passed between the
+//! syscall wrappers and this module without conversion.
+
+use core::cmp::Ordering;
+
+/// Compare two little-endian integers.
+pub(crate) const fn cmp<const N: usize>(a: &[u32; N], b: &[u32; N]) -> Ordering {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] > b[i] {
+            return Ordering::Greater;
+        }
+        if a[i] < b[i] {
+            return Ordering::Less;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compute `a + b`, returning the sum and the carry out.
+pub(crate) const fn add<const N: usize>(a: &[u32; N], b: &[u32; N]) -> ([u32; N], bool) {
+    let mut r = [0u32; N];
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < N {
+        let s = a[i] as u64 + b[i] as u64 + carry;
+        r[i] = s as u32;
+        carry = s >> 32;
+        i += 1;
+    }
+    (r, carry != 0)
+}
+
+/// Compute `a - b`, returning the difference and the borrow out.
+pub(crate) const fn sub<const N: usize>(a: &[u32; N], b: &[u32; N]) -> ([u32; N], bool) {
+    let mut r = [0u32; N];
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < N {
+        let d = (a[i] as u64).wrapping_sub(b[i] as u64).wrapping_sub(borrow);
+        r[i] = d as u32;
+        borrow = (d >> 63) & 1;
+        i += 1;
+    }
+    (r, borrow != 0)
+}
+
+/// Returns `true` if every limb of `a` is zero.
+pub(crate) const fn is_zero<const N: usize>(a: &[u32; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The integer `1`.
+pub(crate) const fn one<const N: usize>() -> [u32; N] {
+    let mut r = [0u32; N];
+    r[0] = 1;
+    r
+}
+
+/// Compute the full product `a * b`, returning its low and high `N` limbs.
+pub(crate) const fn mul_wide<const N: usize>(a: &[u32; N], b: &[u32; N]) -> ([u32; N], [u32; N]) {
+    let mut lo = [0u32; N];
+    let mut hi = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        let mut carry = 0u64;
+        let mut j = 0;
+        while j < N {
+            let k = i + j;
+            let t = if k < N { lo[k] } else { hi[k - N] };
+            let s = t as u64 + a[j] as u64 * b[i] as u64 + carry;
+            if k < N {
+                lo[k] = s as u32;
+            } else {
+                hi[k - N] = s as u32;
+            }
+            carry = s >> 32;
+            j += 1;
+        }
+        // Limb `i + N` has not been written by the previous rows.
+        hi[i] = carry as u32;
+        i += 1;
+    }
+    (lo, hi)
+}
+
+/// Divide the `2N`-limb integer `lo + hi * 2^(32 * N)` by `m != 0`, returning the low `N`
+/// limbs of the quotient and the remainder.
+///
+/// This is plain binary long division, for moduli that are not known in advance.
+pub(crate) const fn div_rem_wide<const N: usize>(
+    lo: &[u32; N],
+    hi: &[u32; N],
+    m: &[u32; N],
+) -> ([u32; N], [u32; N]) {
+    let mut q = [0u32; N];
+    let mut r = [0u32; N];
+    let mut i = 64 * N;
+    while i > 0 {
+        i -= 1;
+        let bit = if i < 32 * N {
+            (lo[i / 32] >> (i % 32)) & 1
+        } else {
+            (hi[(i - 32 * N) / 32] >> (i % 32)) & 1
+        };
+
+        // r = 2 * r + bit, where the bit shifted out is part of the true value.
+        let top = r[N - 1] >> 31;
+        let mut j = N - 1;
+        while j > 0 {
+            r[j] = (r[j] << 1) | (r[j - 1] >> 31);
+            j -= 1;
+        }
+        r[0] = (r[0] << 1) | bit;
+
+        if top == 1 || !matches!(cmp(&r, m), Ordering::Less) {
+            r = sub(&r, m).0;
+            if i < 32 * N {
+                q[i / 32] |= 1 << (i % 32);
+            }
+        }
+    }
+    (q, r)
+}
+
+/// Read an `N`-limb integer from `4 * N` big-endian bytes.
+pub(crate) fn from_be_bytes<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    assert_eq!(bytes.len(), 4 * N);
+    core::array::from_fn(|i| {
+        let j = 4 * (N - 1 - i);
+        u32::from_be_bytes([bytes[j], bytes[j + 1], bytes[j + 2], bytes[j + 3]])
+    })
+}
+
+/// Write an `N`-limb integer as `4 * N` big-endian bytes.
+pub(crate) fn to_be_bytes<const N: usize>(a: &[u32; N], bytes: &mut [u8]) {
+    assert_eq!(bytes.len(), 4 * N);
+    for (chunk, limb) in bytes.chunks_exact_mut(4).zip(a.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+}
+
+/// An odd modulus together with its Montgomery constants, for `R = 2^(32 * N)`.
+pub(crate) struct Modulus<const N: usize> {
+    /// The modulus itself.
+    pub(crate) m: [u32; N],
+    /// `-m^-1 mod 2^32`.
+    inv: u32,
+    /// `R^2 mod m`.
+    r2: [u32; N],
+    /// `R^3 mod m`.
+    r3: [u32; N],
+}
+
+impl<const N: usize> Modulus<N> {
+    /// Precompute the Montgomery constants of the odd modulus `m`.
+    pub(crate) const fn new(m: [u32; N]) -> Self {
+        assert!(m[0] & 1 == 1, "Montgomery modulus must be odd");
+
+        // Newton iteration, each step doubles the number of correct low bits.
+        let mut inv = 1u32;
+        let mut i = 0;
+        while i < 5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(m[0].wrapping_mul(inv)));
+            i += 1;
+        }
+
+        let mut this = Self {
+            m,
+            inv: inv.wrapping_neg(),
+            r2: [0; N],
+            r3: [0; N],
+        };
+
+        // R^2 mod m by repeated doubling of 1.
+        let mut r2 = one::<N>();
+        let mut i = 0;
+        while i < 64 * N {
+            r2 = this.add(&r2, &r2);
+            i += 1;
+        }
+        this.r2 = r2;
+        this.r3 = this.mont_mul(&r2, &r2);
+        this
+    }
+
+    /// Compute `a + b mod m` for `a, b < m`.
+    pub(crate) const fn add(&self, a: &[u32; N], b: &[u32; N]) -> [u32; N] {
+        let (s, carry) = add(a, b);
+        if carry || !matches!(cmp(&s, &self.m), Ordering::Less) {
+            sub(&s, &self.m).0
+        } else {
+            s
+        }
+    }
+
+    /// Compute `a - b mod m` for `a, b < m`.
+    pub(crate) const fn sub(&self, a: &[u32; N], b: &[u32; N]) -> [u32; N] {
+        let (d, borrow) = sub(a, b);
+        if borrow {
+            add(&d, &self.m).0
+        } else {
+            d
+        }
+    }
+
+    /// Compute `-a mod m` for `a < m`.
+    pub(crate) const fn neg(&self, a: &[u32; N]) -> [u32; N] {
+        if is_zero(a) {
+            *a
+        } else {
+            sub(&self.m, a).0
+        }
+    }
+
+    /// Montgomery product `a * b * R^-1 mod m`, for `a * b < m * R`.
+    pub(crate) const fn mont_mul(&self, a: &[u32; N], b: &[u32; N]) -> [u32; N] {
+        let mut t = [0u32; N];
+        let mut t_hi = 0u32;
+        let mut i = 0;
+        while i < N {
+            let mut carry = 0u64;
+            let mut j = 0;
+            while j < N {
+                let s = t[j] as u64 + a[j] as u64 * b[i] as u64 + carry;
+                t[j] = s as u32;
+                carry = s >> 32;
+                j += 1;
+            }
+            let s = t_hi as u64 + carry;
+            t_hi = s as u32;
+            let t_top = (s >> 32) as u32;
+
+            let q = t[0].wrapping_mul(self.inv) as u64;
+            let mut carry = (t[0] as u64 + q * self.m[0] as u64) >> 32;
+            let mut j = 1;
+            while j < N {
+                let s = t[j] as u64 + q * self.m[j] as u64 + carry;
+                t[j - 1] = s as u32;
+                carry = s >> 32;
+                j += 1;
+            }
+            let s = t_hi as u64 + carry;
+            t[N - 1] = s as u32;
+            t_hi = t_top + (s >> 32) as u32;
+            i += 1;
+        }
+        if t_hi != 0 || !matches!(cmp(&t, &self.m), Ordering::Less) {
+            sub(&t, &self.m).0
+        } else {
+            t
+        }
+    }
+
+    /// Compute `a * b mod m` for `a, b < m`.
+    pub(crate) const fn mul(&self, a: &[u32; N], b: &[u32; N]) -> [u32; N] {
+        self.mont_mul(&self.mont_mul(a, b), &self.r2)
+    }
+
+    /// Reduce an arbitrary `N`-limb integer modulo `m`.
+    pub(crate) const fn reduce(&self, a: &[u32; N]) -> [u32; N] {
+        self.mont_mul(&self.mont_mul(a, &self.r2), &one())
+    }
+
+    /// Reduce the `2N`-limb integer `lo + hi * R` modulo `m`.
+    pub(crate) const fn reduce_wide(&self, lo: &[u32; N], hi: &[u32; N]) -> [u32; N] {
+        let lo = self.mont_mul(lo, &self.r2);
+        let hi = self.mont_mul(hi, &self.r3);
+        self.mont_mul(&self.add(&lo, &hi), &one())
+    }
+
+    /// Compute `a^e mod m` for `a < m`, with the exponent given as little-endian limbs.
+    pub(crate) const fn pow<const E: usize>(&self, a: &[u32; N], e: &[u32; E]) -> [u32; N] {
+        let base = self.mont_mul(a, &self.r2);
+        let mut acc = self.mont_mul(&one(), &self.r2);
+        let mut i = 32 * E;
+        while i > 0 {
+            i -= 1;
+            acc = self.mont_mul(&acc, &acc);
+            if (e[i / 32] >> (i % 32)) & 1 == 1 {
+                acc = self.mont_mul(&acc, &base);
+            }
+        }
+        self.mont_mul(&acc, &one())
+    }
+
+    /// Compute `a^-1 mod m` for a prime `m`, by Fermat's little theorem.
+    ///
+    /// Returns zero when `a` is zero.
+    pub(crate) const fn inv(&self, a: &[u32; N]) -> [u32; N] {
+        let two = {
+            let mut two = [0u32; N];
+            two[0] = 2;
+            two
+        };
+        self.pow(a, &sub(&self.m, &two).0)
+    }
+
+    /// Returns `true` if `a < m` is a nonzero square modulo the prime `m`, by Euler's criterion.
+    pub(crate) const fn is_square(&self, a: &[u32; N]) -> bool {
+        // (m - 1) / 2
+        let mut e = sub(&self.m, &one()).0;
+        let mut i = 0;
+        while i < N {
+            e[i] >>= 1;
+            if i + 1 < N {
+                e[i] |= e[i + 1] << 31;
+            }
+            i += 1;
+        }
+        let r = self.pow(a, &e);
+        matches!(cmp(&r, &one()), Ordering::Equal)
+    }
+}
@@ -0,0 +1,96 @@
+//! BLS12-381 G1 curve operations.
+//!
+//! Points are affine `x || y`, each coordinate 12 little-endian `u32` limbs. The point at
+//! infinity has no representation.
+
+use crate::arith::Modulus;
+use crate::{checked, SyscallCode};
+
+/// `BLS12381_ADD` syscall ID.
+pub const BLS12381_ADD: u32 = SyscallCode::Bls12381Add.as_u32();
+
+/// `BLS12381_DOUBLE` syscall ID.
+pub const BLS12381_DOUBLE: u32 = SyscallCode::Bls12381Double.as_u32();
+
+/// `BLS12381_DECOMPRESS` syscall ID.
+pub const BLS12381_DECOMPRESS: u32 = SyscallCode::Bls12381Decompress.as_u32();
+
+/// Perform in-place point addition `p += q`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 24]`, and must remain valid even
+///   when `q` is read for `[u32; 24]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 24]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be points on the curve with canonical coordinates, and `p != ±q`.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_bls12381_add(p: *mut [u32; 24], q: *const [u32; 24]) {
+    unsafe {
+        checked::binary(p, q, Some(&FIELD));
+        crate::syscall!(BLS12381_ADD, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place point doubling `p = 2 * p`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 24]`.
+///
+/// * `p` must be properly aligned.
+///
+/// * `p` must be a point on the curve with canonical coordinates.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_bls12381_double(p: *mut [u32; 24]) {
+    unsafe {
+        checked::unary(p, Some(&FIELD));
+        crate::syscall!(BLS12381_DOUBLE, p, 0; options(nostack))
+    }
+}
+
+/// Recover the `y` coordinate of a point from its `x` coordinate.
+///
+/// `point[48..96]` holds `x` as little-endian bytes. The syscall writes the `y` whose parity
+/// is `is_odd` to `point[0..48]`, as little-endian bytes.
+///
+/// This is the layout of the other Weierstrass curves, not the big-endian one of compressed
+/// points: reverse the bytes of a compressed `x`, with its flag bits cleared, into the upper
+/// half first.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `point` must be [valid] for reads and writes of `[u8; 96]`.
+///
+/// * `point` must be aligned to 4 bytes.
+///
+/// * `x` must be the coordinate of a point on the curve.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_bls12381_decompress(point: *mut [u8; 96], is_odd: bool) {
+    checked::pointer("point", point);
+    unsafe { crate::syscall!(BLS12381_DECOMPRESS, point, is_odd as u32; options(nostack)) }
+}
+
+/// The BLS12-381 base field modulus `p`.
+pub(crate) const FIELD: Modulus<12> = Modulus::new([
+    0xffffaaab, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84,
+    0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+]);
@@ -0,0 +1,131 @@
+//! [`ark_ff`] backend for the bn254 scalar field.
+//!
+//! [`Fr`] is a drop-in replacement for `ark_bn254::Fr`, whose every multiplication is
+//! delegated to the `BN254_SCALAR_MUL` and `BN254_SCALAR_MAC` syscalls instead of being
+//! computed in software.
+//!
+//! Unlike `ark_bn254::Fr`, elements are stored in canonical form rather than in Montgomery
+//! form, since the syscalls multiply canonical values: each product is a single syscall. Only
+//! code reading the representation `Fp::0` directly instead of [`PrimeField::into_bigint`] sees
+//! the difference.
+//!
+//! [`PrimeField::into_bigint`]: ark_ff::PrimeField::into_bigint
+
+use core::marker::PhantomData;
+
+use ark_ff::{BigInt, Fp, Fp256, FpConfig, SqrtPrecomputation};
+
+use super::fr::{R_MINUS_TWO, TRACE_MINUS_ONE_DIV_TWO};
+
+/// The bn254 scalar field, with multiplication accelerated by the bn254 scalar syscalls.
+pub type Fr = Fp256<FrConfig>;
+
+/// [`FpConfig`] of the bn254 scalar field backed by the bn254 scalar syscalls.
+pub struct FrConfig;
+
+impl FpConfig<4> for FrConfig {
+    const MODULUS: BigInt<4> = BigInt([
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    /// `5`, as in `ark_bn254`.
+    const GENERATOR: Fr = Fp(BigInt([5, 0, 0, 0]), PhantomData);
+
+    const ZERO: Fr = Fp(BigInt([0; 4]), PhantomData);
+
+    const ONE: Fr = Fp(BigInt([1, 0, 0, 0]), PhantomData);
+
+    const TWO_ADICITY: u32 = 28;
+
+    /// `GENERATOR^t` for `r - 1 = 2^28 * t`.
+    const TWO_ADIC_ROOT_OF_UNITY: Fr = Fp(
+        BigInt([
+            0x9bd61b6e725b19f0,
+            0x402d111e41112ed4,
+            0x00e0a7eb8ef62abc,
+            0x2a3c09f0a58a7e85,
+        ]),
+        PhantomData,
+    );
+
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Fr>> = Some(SqrtPrecomputation::TonelliShanks {
+        two_adicity: Self::TWO_ADICITY,
+        quadratic_nonresidue_to_trace: Self::TWO_ADIC_ROOT_OF_UNITY,
+        trace_of_modulus_minus_one_div_two: &TRACE_MINUS_ONE_DIV_TWO,
+    });
+
+    fn add_assign(a: &mut Fr, b: &Fr) {
+        *a = from_fr(to_fr(a) + to_fr(b));
+    }
+
+    fn sub_assign(a: &mut Fr, b: &Fr) {
+        *a = from_fr(to_fr(a) - to_fr(b));
+    }
+
+    fn double_in_place(a: &mut Fr) {
+        let a_fr = to_fr(a);
+        *a = from_fr(a_fr + a_fr);
+    }
+
+    fn neg_in_place(a: &mut Fr) {
+        *a = from_fr(-to_fr(a));
+    }
+
+    fn mul_assign(a: &mut Fr, b: &Fr) {
+        *a = from_fr(to_fr(a) * to_fr(b));
+    }
+
+    fn sum_of_products<const T: usize>(a: &[Fr; T], b: &[Fr; T]) -> Fr {
+        from_fr(a.iter().zip(b).fold(super::Fr::ZERO, |acc, (a, b)| {
+            to_fr(a).mul_add(&to_fr(b), &acc)
+        }))
+    }
+
+    fn square_in_place(a: &mut Fr) {
+        let a_fr = to_fr(a);
+        *a = from_fr(a_fr * a_fr);
+    }
+
+    fn inverse(a: &Fr) -> Option<Fr> {
+        let a = to_fr(a);
+        if a.is_zero() {
+            return None;
+        }
+        Some(from_fr(a.pow(&R_MINUS_TWO)))
+    }
+
+    fn from_bigint(other: BigInt<4>) -> Option<Fr> {
+        super::Fr::from_limbs(to_limbs(&other)).map(from_fr)
+    }
+
+    fn into_bigint(other: Fr) -> BigInt<4> {
+        other.0
+    }
+}
+
+/// Split 64-bit limbs into 32-bit limbs.
+fn to_limbs(a: &BigInt<4>) -> [u32; 8] {
+    core::array::from_fn(|i| (a.0[i / 2] >> (32 * (i % 2))) as u32)
+}
+
+/// Join 32-bit limbs into 64-bit limbs.
+fn from_limbs(limbs: [u32; 8]) -> BigInt<4> {
+    BigInt(core::array::from_fn(|i| {
+        limbs[2 * i] as u64 | (limbs[2 * i + 1] as u64) << 32
+    }))
+}
+
+/// View the representation of `a` as an [`Fr`](super::Fr).
+///
+/// The representation of an `Fp` is always reduced below `r`, so this is a valid `Fr`.
+fn to_fr(a: &Fr) -> super::Fr {
+    super::Fr(to_limbs(&a.0))
+}
+
+/// Store an [`Fr`](super::Fr) as the representation of an `Fp`.
+fn from_fr(a: super::Fr) -> Fr {
+    Fp(from_limbs(a.to_limbs()), PhantomData)
+}
@@ -0,0 +1,118 @@
+//! [`ff`] trait implementations for the bn254 scalar field.
+
+use ::ff::helpers::{sqrt_ratio_generic, sqrt_tonelli_shanks};
+use ::ff::{Field, FromUniformBytes, PrimeField};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use super::fr::{MODULUS, R_MINUS_TWO, TRACE_MINUS_ONE_DIV_TWO, TWO_POW_256};
+use super::Fr;
+use crate::arith;
+
+impl ConstantTimeEq for Fr {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for Fr {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(core::array::from_fn(|i| {
+            u32::conditional_select(&a.0[i], &b.0[i], choice)
+        }))
+    }
+}
+
+impl Field for Fr {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Self::from_uniform_bytes(&bytes)
+    }
+
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    fn double(&self) -> Self {
+        *self + *self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        CtOption::new(Fr::pow(self, &R_MINUS_TWO), !Field::is_zero(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        sqrt_ratio_generic(num, div)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        sqrt_tonelli_shanks(self, TRACE_MINUS_ONE_DIV_TWO)
+    }
+}
+
+impl PrimeField for Fr {
+    type Repr = [u8; 32];
+
+    const MODULUS: &'static str =
+        "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+    const NUM_BITS: u32 = 254;
+    const CAPACITY: u32 = 253;
+    const TWO_INV: Self = Self([
+        0xf8000001, 0xa1f0fac9, 0x3cdcb848, 0x9419f424, 0x40c0ac2e, 0xdc2822db, 0x7098d014,
+        0x18322739,
+    ]);
+    const MULTIPLICATIVE_GENERATOR: Self = Self([7, 0, 0, 0, 0, 0, 0, 0]);
+    const S: u32 = 28;
+    const ROOT_OF_UNITY: Self = Self([
+        0x60c37c9c, 0xd34f1ed9, 0xd39329c8, 0x3215cf6d, 0x3dd31f74, 0x98865ea9, 0x166d18b7,
+        0x03ddb9f5,
+    ]);
+    const ROOT_OF_UNITY_INV: Self = Self([
+        0x414e6dba, 0x0ed3e50a, 0x9115aba7, 0xb22625f5, 0x80f34361, 0x1bbe5871, 0x4daabc26,
+        0x04812717,
+    ]);
+    const DELTA: Self = Self([
+        0xe533e9a2, 0x870e56bb, 0x5e963f25, 0x5b5f898e, 0xd4c86e71, 0x64ec26aa, 0x22c6f0ca,
+        0x09226b6e,
+    ]);
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let value = Self::from_bytes(&repr);
+        CtOption::new(
+            value.unwrap_or_default(),
+            Choice::from(value.is_some() as u8),
+        )
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.to_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.0[0] & 1) as u8)
+    }
+}
+
+impl FromUniformBytes<64> for Fr {
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        let lo = reduce(&bytes[..32]);
+        let hi = reduce(&bytes[32..]);
+        // lo + hi * 2^256
+        hi.mul_add(&TWO_POW_256, &lo)
+    }
+}
+
+/// Reduce 32 little-endian bytes modulo `r`.
+fn reduce(bytes: &[u8]) -> Fr {
+    let mut limbs: [u32; 8] =
+        core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()));
+    // 2^256 < 6r, so at most five subtractions are needed.
+    while arith::cmp(&limbs, &MODULUS.m).is_ge() {
+        limbs = arith::sub(&limbs, &MODULUS.m).0;
+    }
+    Fr(limbs)
+}
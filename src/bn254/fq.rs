@@ -0,0 +1,399 @@
+//! bn254 base field and its quadratic extension backed by the field syscalls.
+//!
+//! SP1 v1 has no base field precompiles: when the selected syscall table lacks them, the same
+//! types compute in software instead.
+
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::arith::{self, Modulus};
+
+/// The bn254 base field modulus `q`.
+pub(crate) const MODULUS: Modulus<8> = Modulus::new([
+    0xd87cfd47, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+]);
+
+/// `q - 2`, the exponent of the Fermat inversion.
+const Q_MINUS_TWO: [u32; 8] = [
+    0xd87cfd45, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// `(q + 1) / 4`, the exponent of the square root since `q = 3 mod 4`.
+const Q_PLUS_ONE_DIV_FOUR: [u32; 8] = [
+    0xb61f3f52, 0x4f082305, 0x5a1c72a3, 0x65e05aa4, 0xa0605617, 0x6e14116d, 0xb84c680a, 0x0c19139c,
+];
+
+/// `(q - 3) / 4`.
+const Q_MINUS_THREE_DIV_FOUR: [u32; 8] = [
+    0xb61f3f51, 0x4f082305, 0x5a1c72a3, 0x65e05aa4, 0xa0605617, 0x6e14116d, 0xb84c680a, 0x0c19139c,
+];
+
+/// `(q - 1) / 2`.
+const Q_MINUS_ONE_DIV_TWO: [u32; 8] = [
+    0x6c3e7ea3, 0x9e10460b, 0xb438e546, 0xcbc0b548, 0x40c0ac2e, 0xdc2822db, 0x7098d014, 0x18322739,
+];
+
+/// An element of the bn254 base field `Fq`.
+///
+/// The value is stored as canonical little-endian `u32` limbs, i.e. always reduced below `q`.
+/// This is exactly the layout expected by the `BN254_FP_ADD` syscall and its siblings, so
+/// arithmetic never needs `unsafe` on the caller side.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C, align(4))]
+pub struct Fq(pub(crate) [u32; 8]);
+
+impl Fq {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; 8]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self(arith::one());
+
+    /// Create an element from little-endian limbs, returning `None` if `limbs >= q`.
+    pub const fn from_limbs(limbs: [u32; 8]) -> Option<Self> {
+        match arith::cmp(&limbs, &MODULUS.m) {
+            core::cmp::Ordering::Less => Some(Self(limbs)),
+            _ => None,
+        }
+    }
+
+    /// The canonical little-endian limbs of the element.
+    pub const fn to_limbs(&self) -> [u32; 8] {
+        self.0
+    }
+
+    /// Create an element from its little-endian byte encoding, returning `None` if the
+    /// encoded integer is not less than `q`.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0u32; 8];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(4)) {
+            *limb = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// The canonical little-endian byte encoding of the element.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(4).zip(self.0) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns `true` if the element is zero.
+    pub const fn is_zero(&self) -> bool {
+        arith::is_zero(&self.0)
+    }
+
+    /// Compute `self^2`.
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Compute `2 * self`.
+    pub fn double(&self) -> Self {
+        *self + *self
+    }
+
+    /// Compute `self^exp` for an exponent given as little-endian limbs.
+    pub fn pow<const E: usize>(&self, exp: &[u32; E]) -> Self {
+        let mut acc = Self::ONE;
+        for i in (0..32 * E).rev() {
+            acc = acc.square();
+            if (exp[i / 32] >> (i % 32)) & 1 == 1 {
+                acc *= self;
+            }
+        }
+        acc
+    }
+
+    /// Compute `self^-1`, or `None` if the element is zero.
+    pub fn invert(&self) -> Option<Self> {
+        (!self.is_zero()).then(|| self.pow(&Q_MINUS_TWO))
+    }
+
+    /// Compute a square root of `self`, or `None` if the element is not a square.
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = self.pow(&Q_PLUS_ONE_DIV_FOUR);
+        (root.square() == *self).then_some(root)
+    }
+}
+
+impl From<u64> for Fq {
+    fn from(value: u64) -> Self {
+        let mut limbs = [0u32; 8];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        Self(limbs)
+    }
+}
+
+impl Neg for Fq {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(MODULUS.neg(&self.0))
+    }
+}
+
+/// An element of the quadratic extension `Fq2 = Fq[u] / (u^2 + 1)`.
+///
+/// The value is stored as `c0 || c1` for `c0 + c1 * u`, each coefficient canonical
+/// little-endian `u32` limbs. This is exactly the layout expected by the `BN254_FP2_ADD`
+/// syscall and its siblings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C, align(4))]
+pub struct Fq2(pub(crate) [u32; 16]);
+
+impl Fq2 {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; 16]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::new(Fq::ONE, Fq::ZERO);
+
+    /// Create the element `c0 + c1 * u`.
+    pub const fn new(c0: Fq, c1: Fq) -> Self {
+        let mut limbs = [0u32; 16];
+        let mut i = 0;
+        while i < 8 {
+            limbs[i] = c0.0[i];
+            limbs[8 + i] = c1.0[i];
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    /// The coefficient `c0`.
+    pub fn c0(&self) -> Fq {
+        Fq(self.0[..8].try_into().unwrap())
+    }
+
+    /// The coefficient `c1` of `u`.
+    pub fn c1(&self) -> Fq {
+        Fq(self.0[8..].try_into().unwrap())
+    }
+
+    /// Returns `true` if the element is zero.
+    pub const fn is_zero(&self) -> bool {
+        arith::is_zero(&self.0)
+    }
+
+    /// Compute the conjugate `c0 - c1 * u`, i.e. the `q`-power Frobenius map.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0(), -self.c1())
+    }
+
+    /// Compute `self^2`.
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Compute `2 * self`.
+    pub fn double(&self) -> Self {
+        *self + *self
+    }
+
+    /// Compute `self^exp` for an exponent given as little-endian limbs.
+    pub fn pow<const E: usize>(&self, exp: &[u32; E]) -> Self {
+        let mut acc = Self::ONE;
+        for i in (0..32 * E).rev() {
+            acc = acc.square();
+            if (exp[i / 32] >> (i % 32)) & 1 == 1 {
+                acc *= self;
+            }
+        }
+        acc
+    }
+
+    /// Compute `self^-1`, or `None` if the element is zero.
+    pub fn invert(&self) -> Option<Self> {
+        // (c0 + c1 * u)^-1 = (c0 - c1 * u) / (c0^2 + c1^2)
+        let (c0, c1) = (self.c0(), self.c1());
+        let inv = (c0.square() + c1.square()).invert()?;
+        Some(Self::new(c0 * inv, -(c1 * inv)))
+    }
+
+    /// Compute a square root of `self`, or `None` if the element is not a square.
+    pub fn sqrt(&self) -> Option<Self> {
+        // Algorithm 9 of Adj and Rodríguez-Henríquez, "Square root computation over even
+        // extension fields", for `q = 3 mod 4`.
+        let minus_one = -Self::ONE;
+        let a1 = self.pow(&Q_MINUS_THREE_DIV_FOUR);
+        let alpha = a1.square() * self;
+        let x0 = a1 * self;
+        let root = if alpha == minus_one {
+            x0 * Self::new(Fq::ZERO, Fq::ONE)
+        } else {
+            (alpha + Self::ONE).pow(&Q_MINUS_ONE_DIV_TWO) * x0
+        };
+        (root.square() == *self).then_some(root)
+    }
+}
+
+impl From<Fq> for Fq2 {
+    fn from(c0: Fq) -> Self {
+        Self::new(c0, Fq::ZERO)
+    }
+}
+
+impl Neg for Fq2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0(), -self.c1())
+    }
+}
+
+/// Implement the field operators of `$ty` with the in-place syscalls, and forward the
+/// by-reference operators to the by-value implementations.
+macro_rules! impl_field_ops {
+    ($ty:ident, $zero:expr, $one:expr; $($op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident => $syscall:path;)*) => {$(
+        impl $op for $ty {
+            type Output = $ty;
+
+            fn $op_fn(mut self, rhs: $ty) -> $ty {
+                $assign::$assign_fn(&mut self, rhs);
+                self
+            }
+        }
+
+        impl $assign for $ty {
+            fn $assign_fn(&mut self, rhs: $ty) {
+                // SAFETY: `self` is borrowed mutably and `rhs` is a separate value, so both
+                // pointers are valid, 4-byte aligned and do not overlap. The limbs of every
+                // element are canonical, so the result is canonical as well.
+                unsafe { $syscall(&mut self.0, &rhs.0) };
+            }
+        }
+
+        impl<'a> $op<&'a $ty> for $ty {
+            type Output = $ty;
+
+            fn $op_fn(self, rhs: &'a $ty) -> $ty {
+                $op::$op_fn(self, *rhs)
+            }
+        }
+
+        impl<'a> $assign<&'a $ty> for $ty {
+            fn $assign_fn(&mut self, rhs: &'a $ty) {
+                $assign::$assign_fn(self, *rhs)
+            }
+        }
+    )*
+
+        impl Sum for $ty {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($zero, Add::add)
+            }
+        }
+
+        impl<'a> Sum<&'a $ty> for $ty {
+            fn sum<I: Iterator<Item = &'a $ty>>(iter: I) -> Self {
+                iter.fold($zero, Add::add)
+            }
+        }
+
+        impl Product for $ty {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($one, Mul::mul)
+            }
+        }
+
+        impl<'a> Product<&'a $ty> for $ty {
+            fn product<I: Iterator<Item = &'a $ty>>(iter: I) -> Self {
+                iter.fold($one, Mul::mul)
+            }
+        }
+    };
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        sp1_syscall = "BN254_FP_ADD",
+        sp1_syscall = "BN254_FP_SUB",
+        sp1_syscall = "BN254_FP_MUL",
+        sp1_syscall = "BN254_FP2_ADD",
+        sp1_syscall = "BN254_FP2_SUB",
+        sp1_syscall = "BN254_FP2_MUL",
+    ))] {
+        use super::{
+            syscall_bn254_fp2_addmod as fp2_addmod, syscall_bn254_fp2_mulmod as fp2_mulmod,
+            syscall_bn254_fp2_submod as fp2_submod, syscall_bn254_fp_addmod as fp_addmod,
+            syscall_bn254_fp_mulmod as fp_mulmod, syscall_bn254_fp_submod as fp_submod,
+        };
+    } else {
+        use soft::{fp2_addmod, fp2_mulmod, fp2_submod, fp_addmod, fp_mulmod, fp_submod};
+    }
+}
+
+impl_field_ops! {
+    Fq, Fq::ZERO, Fq::ONE;
+    Add::add, AddAssign::add_assign => fp_addmod;
+    Sub::sub, SubAssign::sub_assign => fp_submod;
+    Mul::mul, MulAssign::mul_assign => fp_mulmod;
+}
+
+impl_field_ops! {
+    Fq2, Fq2::ZERO, Fq2::ONE;
+    Add::add, AddAssign::add_assign => fp2_addmod;
+    Sub::sub, SubAssign::sub_assign => fp2_submod;
+    Mul::mul, MulAssign::mul_assign => fp2_mulmod;
+}
+
+/// The field operations in software, for SP1 releases without the base field precompiles.
+///
+/// They have the signatures of the syscall wrappers, and the same contract.
+#[cfg(not(all(
+    sp1_syscall = "BN254_FP_ADD",
+    sp1_syscall = "BN254_FP_SUB",
+    sp1_syscall = "BN254_FP_MUL",
+    sp1_syscall = "BN254_FP2_ADD",
+    sp1_syscall = "BN254_FP2_SUB",
+    sp1_syscall = "BN254_FP2_MUL",
+)))]
+mod soft {
+    use super::MODULUS as Q;
+
+    pub(super) unsafe fn fp_addmod(p: *mut [u32; 8], q: *const [u32; 8]) {
+        unsafe { *p = Q.add(&*p, &*q) }
+    }
+
+    pub(super) unsafe fn fp_submod(p: *mut [u32; 8], q: *const [u32; 8]) {
+        unsafe { *p = Q.sub(&*p, &*q) }
+    }
+
+    pub(super) unsafe fn fp_mulmod(p: *mut [u32; 8], q: *const [u32; 8]) {
+        unsafe { *p = Q.mul(&*p, &*q) }
+    }
+
+    pub(super) unsafe fn fp2_addmod(p: *mut [u32; 16], q: *const [u32; 16]) {
+        let ([a0, a1], [b0, b1]) = unsafe { (split(&*p), split(&*q)) };
+        unsafe { *p = join(&Q.add(&a0, &b0), &Q.add(&a1, &b1)) }
+    }
+
+    pub(super) unsafe fn fp2_submod(p: *mut [u32; 16], q: *const [u32; 16]) {
+        let ([a0, a1], [b0, b1]) = unsafe { (split(&*p), split(&*q)) };
+        unsafe { *p = join(&Q.sub(&a0, &b0), &Q.sub(&a1, &b1)) }
+    }
+
+    pub(super) unsafe fn fp2_mulmod(p: *mut [u32; 16], q: *const [u32; 16]) {
+        // (a0 + a1 * u) * (b0 + b1 * u) = (a0 * b0 - a1 * b1) + (a0 * b1 + a1 * b0) * u
+        let ([a0, a1], [b0, b1]) = unsafe { (split(&*p), split(&*q)) };
+        let c0 = Q.sub(&Q.mul(&a0, &b0), &Q.mul(&a1, &b1));
+        let c1 = Q.add(&Q.mul(&a0, &b1), &Q.mul(&a1, &b0));
+        unsafe { *p = join(&c0, &c1) }
+    }
+
+    fn split(a: &[u32; 16]) -> [[u32; 8]; 2] {
+        [a[..8].try_into().unwrap(), a[8..].try_into().unwrap()]
+    }
+
+    fn join(c0: &[u32; 8], c1: &[u32; 8]) -> [u32; 16] {
+        let mut a = [0u32; 16];
+        a[..8].copy_from_slice(c0);
+        a[8..].copy_from_slice(c1);
+        a
+    }
+}
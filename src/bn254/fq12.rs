@@ -0,0 +1,219 @@
+//! The `Fq6` and `Fq12` extensions of the bn254 pairing target group, built on [`Fq2`].
+//!
+//! The tower is `Fq6 = Fq2[v] / (v^3 - xi)` with `xi = 9 + u`, and `Fq12 = Fq6[w] / (w^2 - v)`.
+
+use super::fq::{Fq, Fq2};
+
+/// `xi^((q - 1) / 3)`, the Frobenius coefficient of `v`.
+pub(crate) const FROBENIUS_COEFF_FQ6_C1: Fq2 = Fq2::new(
+    Fq([
+        0x176f553d, 0x99e39557, 0xc2c3330c, 0xb78cc310, 0xf559b143, 0x4c0bec3c, 0x4f7911f7,
+        0x2fb34798,
+    ]),
+    Fq([
+        0x640fcba2, 0x1665d51c, 0x0b7c9dce, 0x32ae2a1d, 0xd75a0794, 0x4ba4cc8b, 0x61ebae20,
+        0x16c9e550,
+    ]),
+);
+
+/// `xi^(2 * (q - 1) / 3)`, the Frobenius coefficient of `v^2`.
+const FROBENIUS_COEFF_FQ6_C2: Fq2 = Fq2::new(
+    Fq([
+        0x921ea762, 0x848a1f55, 0xbe94ec72, 0xd33365f7, 0x5a181e84, 0x80f3c0b7, 0x64eea801,
+        0x05b54f5e,
+    ]),
+    Fq([
+        0xcd2b8126, 0xc13b4711, 0x1bdec763, 0x3685d2ea, 0x3b0b1c92, 0x9f3a80b0, 0xe7fd8aee,
+        0x2c145edb,
+    ]),
+);
+
+/// `xi^((q - 1) / 6)`, the Frobenius coefficient of `w`.
+const FROBENIUS_COEFF_FQ12_C1: Fq2 = Fq2::new(
+    Fq([
+        0xdcc9e470, 0xd60b35da, 0x292f2176, 0x5c521e08, 0x76e68b60, 0xe8b99fdd, 0x2865a7df,
+        0x1284b71c,
+    ]),
+    Fq([
+        0x80f362ac, 0xca5cf05f, 0x8eeec7e5, 0x74799277, 0x12150b8e, 0xa6327cfe, 0xb4fae7e6,
+        0x246996f3,
+    ]),
+);
+
+/// Multiply by the non-residue `xi = 9 + u`.
+pub(crate) fn mul_by_xi(a: &Fq2) -> Fq2 {
+    // (c0 + c1 * u) * (9 + u) = (9 * c0 - c1) + (9 * c1 + c0) * u
+    let (c0, c1) = (a.c0(), a.c1());
+    let (c0_8, c1_8) = (c0.double().double().double(), c1.double().double().double());
+    Fq2::new(c0_8 + c0 - c1, c1_8 + c1 + c0)
+}
+
+/// An element `c0 + c1 * v + c2 * v^2` of `Fq6`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fq6 {
+    c0: Fq2,
+    c1: Fq2,
+    c2: Fq2,
+}
+
+impl Fq6 {
+    const ZERO: Self = Self::new(Fq2::ZERO, Fq2::ZERO, Fq2::ZERO);
+
+    const ONE: Self = Self::new(Fq2::ONE, Fq2::ZERO, Fq2::ZERO);
+
+    const fn new(c0: Fq2, c1: Fq2, c2: Fq2) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::new(self.c0 + other.c0, self.c1 + other.c1, self.c2 + other.c2)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Self::new(self.c0 - other.c0, self.c1 - other.c1, self.c2 - other.c2)
+    }
+
+    fn neg(&self) -> Self {
+        Self::new(-self.c0, -self.c1, -self.c2)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        // Karatsuba over the three coefficients.
+        let (a, b) = (self, other);
+        let v0 = a.c0 * b.c0;
+        let v1 = a.c1 * b.c1;
+        let v2 = a.c2 * b.c2;
+        let c0 = mul_by_xi(&((a.c1 + a.c2) * (b.c1 + b.c2) - v1 - v2)) + v0;
+        let c1 = (a.c0 + a.c1) * (b.c0 + b.c1) - v0 - v1 + mul_by_xi(&v2);
+        let c2 = (a.c0 + a.c2) * (b.c0 + b.c2) - v0 - v2 + v1;
+        Self::new(c0, c1, c2)
+    }
+
+    /// Multiply by the sparse element `b0 + b1 * v`.
+    fn mul_by_01(&self, b0: &Fq2, b1: &Fq2) -> Self {
+        let a_a = self.c0 * b0;
+        let b_b = self.c1 * b1;
+        let c0 = mul_by_xi(&(self.c2 * b1)) + a_a;
+        let c1 = (*b0 + b1) * (self.c0 + self.c1) - a_a - b_b;
+        let c2 = self.c2 * b0 + b_b;
+        Self::new(c0, c1, c2)
+    }
+
+    /// Multiply every coefficient by `b`.
+    fn scale(&self, b: &Fq2) -> Self {
+        Self::new(self.c0 * b, self.c1 * b, self.c2 * b)
+    }
+
+    /// Multiply by `v`.
+    fn mul_by_v(&self) -> Self {
+        Self::new(mul_by_xi(&self.c2), self.c0, self.c1)
+    }
+
+    fn invert(&self) -> Option<Self> {
+        let t0 = self.c0.square() - mul_by_xi(&(self.c1 * self.c2));
+        let t1 = mul_by_xi(&self.c2.square()) - self.c0 * self.c1;
+        let t2 = self.c1.square() - self.c0 * self.c2;
+        let norm = self.c0 * t0 + mul_by_xi(&(self.c2 * t1 + self.c1 * t2));
+        let inv = norm.invert()?;
+        Some(Self::new(t0 * inv, t1 * inv, t2 * inv))
+    }
+
+    /// The `q`-power Frobenius map.
+    fn frobenius(&self) -> Self {
+        Self::new(
+            self.c0.conjugate(),
+            self.c1.conjugate() * FROBENIUS_COEFF_FQ6_C1,
+            self.c2.conjugate() * FROBENIUS_COEFF_FQ6_C2,
+        )
+    }
+}
+
+/// An element `c0 + c1 * w` of `Fq12`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fq12 {
+    c0: Fq6,
+    c1: Fq6,
+}
+
+impl Fq12 {
+    /// The multiplicative identity.
+    pub(crate) const ONE: Self = Self {
+        c0: Fq6::ONE,
+        c1: Fq6::ZERO,
+    };
+
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        let v0 = self.c0.mul(&other.c0);
+        let v1 = self.c1.mul(&other.c1);
+        let sum = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1));
+        let c1 = sum.sub(&v0).sub(&v1);
+        Self {
+            c0: v0.add(&v1.mul_by_v()),
+            c1,
+        }
+    }
+
+    pub(crate) fn square(&self) -> Self {
+        // (c0 + c1 * w)^2 = (c0^2 + c1^2 * v) + 2 * c0 * c1 * w
+        let v2 = self.c0.mul(&self.c1);
+        let v0 = self.c0.sub(&self.c1).mul(&self.c0.sub(&self.c1.mul_by_v()));
+        Self {
+            c0: v0.add(&v2).add(&v2.mul_by_v()),
+            c1: v2.add(&v2),
+        }
+    }
+
+    /// Multiply by the sparse element `b0 + (b3 + b4 * v) * w` of a line evaluation.
+    pub(crate) fn mul_by_034(&self, b0: &Fq2, b3: &Fq2, b4: &Fq2) -> Self {
+        let a = self.c0.scale(b0);
+        let b = self.c1.mul_by_01(b3, b4);
+        let e = self.c0.add(&self.c1).mul_by_01(&(*b0 + b3), b4);
+        Self {
+            c0: a.add(&b.mul_by_v()),
+            c1: e.sub(&a.add(&b)),
+        }
+    }
+
+    /// Compute `self^-1`, or `None` if the element is zero.
+    pub(crate) fn invert(&self) -> Option<Self> {
+        let norm = self.c0.mul(&self.c0).sub(&self.c1.mul(&self.c1).mul_by_v());
+        let inv = norm.invert()?;
+        Some(Self {
+            c0: self.c0.mul(&inv),
+            c1: self.c1.mul(&inv).neg(),
+        })
+    }
+
+    /// Compute `c0 - c1 * w`, the `q^6`-power Frobenius map and thus the inverse of an element
+    /// of the cyclotomic subgroup.
+    pub(crate) fn conjugate(&self) -> Self {
+        Self {
+            c0: self.c0,
+            c1: self.c1.neg(),
+        }
+    }
+
+    /// The `q^power` Frobenius map.
+    pub(crate) fn frobenius(&self, power: usize) -> Self {
+        let mut f = *self;
+        for _ in 0..power {
+            f = Self {
+                c0: f.c0.frobenius(),
+                c1: f.c1.frobenius().scale(&FROBENIUS_COEFF_FQ12_C1),
+            };
+        }
+        f
+    }
+
+    /// Compute `self^exp`.
+    pub(crate) fn pow(&self, exp: u64) -> Self {
+        let mut acc = Self::ONE;
+        for i in (0..64 - exp.leading_zeros()).rev() {
+            acc = acc.square();
+            if (exp >> i) & 1 == 1 {
+                acc = acc.mul(self);
+            }
+        }
+        acc
+    }
+}
@@ -0,0 +1,227 @@
+//! bn254 scalar field element backed by the scalar syscalls.
+
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::arith::{self, Modulus};
+
+/// The bn254 scalar field modulus `r`.
+pub(crate) const MODULUS: Modulus<8> = Modulus::new([
+    0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+]);
+
+/// `r - 2`, the exponent of the Fermat inversion.
+#[cfg(any(feature = "ff", feature = "arkworks"))]
+pub(crate) const R_MINUS_TWO: [u64; 4] = [
+    0x43e1f593efffffff,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// `(t - 1) / 2` for `r - 1 = 2^28 * t`, as required by Tonelli-Shanks square roots.
+#[cfg(any(feature = "ff", feature = "arkworks"))]
+pub(crate) const TRACE_MINUS_ONE_DIV_TWO: [u64; 4] = [
+    0xcdcb848a1f0fac9f,
+    0x0c0ac2e9419f4243,
+    0x098d014dc2822db4,
+    0x0000000183227397,
+];
+
+/// `2^256 mod r`.
+#[cfg(feature = "ff")]
+pub(crate) const TWO_POW_256: Fr = Fr([
+    0x4ffffffb, 0xac96341c, 0x9f60cd29, 0x36fc7695, 0x7879462e, 0x666ea36f, 0x9a07df2f, 0x0e0a77c1,
+]);
+
+/// An element of the bn254 scalar field `Fr`.
+///
+/// The value is stored as canonical little-endian `u32` limbs, i.e. always reduced below `r`.
+/// This is exactly the layout expected by [`BN254_SCALAR_MUL`](super::BN254_SCALAR_MUL) and
+/// [`BN254_SCALAR_MAC`](super::BN254_SCALAR_MAC), so arithmetic never needs `unsafe` on the
+/// caller side.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C, align(4))]
+pub struct Fr(pub(crate) [u32; 8]);
+
+impl Fr {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; 8]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self(arith::one());
+
+    /// Create an element from little-endian limbs, returning `None` if `limbs >= r`.
+    pub const fn from_limbs(limbs: [u32; 8]) -> Option<Self> {
+        match arith::cmp(&limbs, &MODULUS.m) {
+            core::cmp::Ordering::Less => Some(Self(limbs)),
+            _ => None,
+        }
+    }
+
+    /// The canonical little-endian limbs of the element.
+    pub const fn to_limbs(&self) -> [u32; 8] {
+        self.0
+    }
+
+    /// Create an element from its little-endian byte encoding, returning `None` if the
+    /// encoded integer is not less than `r`.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0u32; 8];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(4)) {
+            *limb = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// The canonical little-endian byte encoding of the element.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(4).zip(self.0) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns `true` if the element is zero.
+    pub const fn is_zero(&self) -> bool {
+        arith::is_zero(&self.0)
+    }
+
+    /// Compute `self * a + b` with a single `BN254_SCALAR_MAC` syscall.
+    pub fn mul_add(self, a: &Self, b: &Self) -> Self {
+        let mut ret = *b;
+        // SAFETY: `ret` is a local `Fr`, `self` and `a` are distinct `Fr` values, so all three
+        // pointers are valid, 4-byte aligned and do not overlap. The limbs of every `Fr` are
+        // canonical, so the result is canonical as well.
+        unsafe { super::syscall_bn254_scalar_mac(&mut ret.0, &self.0, &a.0) };
+        ret
+    }
+
+    /// Compute `self^exp`, the exponent given as little-endian 64-bit limbs.
+    #[cfg(any(feature = "ff", feature = "arkworks"))]
+    pub(crate) fn pow(&self, exp: &[u64]) -> Self {
+        let mut acc = Self::ONE;
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc * acc;
+                if (limb >> i) & 1 == 1 {
+                    acc *= *self;
+                }
+            }
+        }
+        acc
+    }
+}
+
+impl From<u64> for Fr {
+    fn from(value: u64) -> Self {
+        let mut limbs = [0u32; 8];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        Self(limbs)
+    }
+}
+
+impl Add for Fr {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(MODULUS.add(&self.0, &rhs.0))
+    }
+}
+
+impl AddAssign for Fr {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Fr {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(MODULUS.sub(&self.0, &rhs.0))
+    }
+}
+
+impl SubAssign for Fr {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Fr {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(MODULUS.neg(&self.0))
+    }
+}
+
+impl Mul for Fr {
+    type Output = Self;
+
+    fn mul(mut self, rhs: Self) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl MulAssign for Fr {
+    fn mul_assign(&mut self, rhs: Self) {
+        // SAFETY: `self` is borrowed mutably and `rhs` is a separate `Fr` value, so both
+        // pointers are valid, 4-byte aligned and do not overlap. The limbs of every `Fr` are
+        // canonical, so the result is canonical as well.
+        unsafe { super::syscall_bn254_scalar_mul(&mut self.0, &rhs.0) };
+    }
+}
+
+/// Forward the by-reference operators to the by-value implementations.
+macro_rules! impl_ref_ops {
+    ($($op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident;)*) => {$(
+        impl<'a> $op<&'a Fr> for Fr {
+            type Output = Fr;
+
+            fn $op_fn(self, rhs: &'a Fr) -> Fr {
+                $op::$op_fn(self, *rhs)
+            }
+        }
+
+        impl<'a> $assign<&'a Fr> for Fr {
+            fn $assign_fn(&mut self, rhs: &'a Fr) {
+                $assign::$assign_fn(self, *rhs)
+            }
+        }
+    )*};
+}
+
+impl_ref_ops! {
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+}
+
+impl Sum for Fr {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Fr> for Fr {
+    fn sum<I: Iterator<Item = &'a Fr>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl Product for Fr {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Fr> for Fr {
+    fn product<I: Iterator<Item = &'a Fr>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
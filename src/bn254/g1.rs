@@ -0,0 +1,158 @@
+//! bn254 G1 points backed by the curve syscalls.
+
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Mul, Neg};
+
+use super::fq::MODULUS as FIELD;
+use super::Fr;
+use crate::arith;
+use crate::weierstrass::{Curve, Point};
+
+/// The curve constant `b = 3`.
+const B: [u32; 8] = [3, 0, 0, 0, 0, 0, 0, 0];
+
+/// bn254 G1 for [`Point`] arithmetic.
+struct Bn254;
+
+impl Curve for Bn254 {
+    unsafe fn add_assign(p: &mut [u32; 16], q: &[u32; 16]) {
+        // SAFETY: references are valid, aligned and cannot overlap; the caller guarantees the
+        // curve conditions.
+        unsafe { super::syscall_bn254_add(p, q) }
+    }
+
+    unsafe fn double_assign(p: &mut [u32; 16]) {
+        // SAFETY: as above.
+        unsafe { super::syscall_bn254_double(p) }
+    }
+}
+
+/// A point of the bn254 G1 group in affine coordinates.
+///
+/// The coordinates are canonical little-endian `u32` limbs of a point on `y^2 = x^3 + 3`, or
+/// the point at infinity. Since the point at infinity and `P == ±Q` are handled before any
+/// syscall, arithmetic never needs `unsafe` on the caller side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct G1Affine(Option<[u32; 16]>);
+
+impl G1Affine {
+    /// The point at infinity.
+    pub const IDENTITY: Self = Self(None);
+
+    /// The generator `(1, 2)`.
+    pub const GENERATOR: Self = Self(Some([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]));
+
+    /// Create a point from little-endian coordinate limbs, returning `None` if the
+    /// coordinates are not canonical or the point is not on the curve.
+    pub fn new(x: [u32; 8], y: [u32; 8]) -> Option<Self> {
+        if arith::cmp(&x, &FIELD.m) != Ordering::Less || arith::cmp(&y, &FIELD.m) != Ordering::Less
+        {
+            return None;
+        }
+        let rhs = FIELD.add(&FIELD.mul(&FIELD.mul(&x, &x), &x), &B);
+        if FIELD.mul(&y, &y) != rhs {
+            return None;
+        }
+        let mut xy = [0u32; 16];
+        xy[..8].copy_from_slice(&x);
+        xy[8..].copy_from_slice(&y);
+        Some(Self(Some(xy)))
+    }
+
+    /// The little-endian coordinate limbs `(x, y)`, or `None` for the point at infinity.
+    pub fn to_xy(&self) -> Option<([u32; 8], [u32; 8])> {
+        let xy = self.0?;
+        Some((xy[..8].try_into().unwrap(), xy[8..].try_into().unwrap()))
+    }
+
+    /// Returns `true` if this is the point at infinity.
+    pub const fn is_identity(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Compute `2 * self`.
+    pub fn double(&self) -> Self {
+        Self::from_point(self.to_point().double())
+    }
+
+    /// View the point as a [`Point`].
+    fn to_point(self) -> Point<Bn254> {
+        match self.0 {
+            // SAFETY: a `G1Affine` is always on the curve with canonical coordinates.
+            Some(xy) => unsafe { Point::new_unchecked(xy) },
+            None => Point::IDENTITY,
+        }
+    }
+
+    fn from_point(point: Point<Bn254>) -> Self {
+        Self(point.to_affine())
+    }
+}
+
+impl Default for G1Affine {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Add for G1Affine {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_point(self.to_point().add(&rhs.to_point()))
+    }
+}
+
+impl AddAssign for G1Affine {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<'a> Add<&'a G1Affine> for G1Affine {
+    type Output = G1Affine;
+
+    fn add(self, rhs: &'a G1Affine) -> G1Affine {
+        self + *rhs
+    }
+}
+
+impl<'a> AddAssign<&'a G1Affine> for G1Affine {
+    fn add_assign(&mut self, rhs: &'a G1Affine) {
+        *self = *self + *rhs;
+    }
+}
+
+impl Mul<Fr> for G1Affine {
+    type Output = Self;
+
+    fn mul(self, rhs: Fr) -> Self {
+        Self::from_point(self.to_point().mul(&rhs.to_limbs()))
+    }
+}
+
+impl<'a> Mul<&'a Fr> for G1Affine {
+    type Output = G1Affine;
+
+    fn mul(self, rhs: &'a Fr) -> G1Affine {
+        self * *rhs
+    }
+}
+
+impl Neg for G1Affine {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|mut xy| {
+            let y = FIELD.neg(xy[8..].try_into().unwrap());
+            xy[8..].copy_from_slice(&y);
+            xy
+        }))
+    }
+}
+
+impl core::iter::Sum for G1Affine {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::IDENTITY, Add::add)
+    }
+}
@@ -0,0 +1,181 @@
+//! bn254 G2 points on the sextic twist, for the pairing.
+
+use core::ops::Neg;
+
+use super::fq::{Fq, Fq2};
+
+/// The twist curve constant `b' = 3 / (9 + u)`.
+pub(crate) const B: Fq2 = Fq2::new(
+    Fq([
+        0x24a138e5, 0x3267e6dc, 0x59dbefa3, 0xb5b4c5e5, 0x1be06ac3, 0x81be1899, 0xceb8aaae,
+        0x2b149d40,
+    ]),
+    Fq([
+        0x85c315d2, 0xe4a2bd06, 0xe52d1852, 0xa74fa084, 0xeed8fdf4, 0xcd2cafad, 0x3af0fed4,
+        0x009713b0,
+    ]),
+);
+
+/// A point of the bn254 G2 group in affine coordinates.
+///
+/// The coordinates lie on the twist `y^2 = x^3 + 3 / (9 + u)` over [`Fq2`], and the point is in
+/// the prime-order subgroup, or is the point at infinity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct G2Affine(Option<(Fq2, Fq2)>);
+
+impl G2Affine {
+    /// The point at infinity.
+    pub const IDENTITY: Self = Self(None);
+
+    /// The generator of EIP-197.
+    pub const GENERATOR: Self = Self(Some((
+        Fq2::new(
+            Fq([
+                0xd992f6ed, 0x46debd5c, 0xf75edadd, 0x674322d4, 0x5e5c4479, 0x426a0066, 0x121f1e76,
+                0x1800deef,
+            ]),
+            Fq([
+                0xaef312c2, 0x97e485b7, 0x35a9e712, 0xf1aa4933, 0x31fb5d25, 0x7260bfb7, 0x920d483a,
+                0x198e9393,
+            ]),
+        ),
+        Fq2::new(
+            Fq([
+                0x66fa7daa, 0x4ce6cc01, 0x0c43d37b, 0xe3d1e769, 0x8dcb408f, 0x4aab7180, 0xdb8c6deb,
+                0x12c85ea5,
+            ]),
+            Fq([
+                0xd122975b, 0x55acdadc, 0x70b38ef3, 0xbc4b3133, 0x690c3395, 0xec9e99ad, 0x585ff075,
+                0x090689d0,
+            ]),
+        ),
+    )));
+
+    /// Create a point from its coordinates, returning `None` if the point is not on the twist
+    /// or not in the prime-order subgroup.
+    pub fn new(x: Fq2, y: Fq2) -> Option<Self> {
+        if y.square() != x.square() * x + B {
+            return None;
+        }
+        let point = Self(Some((x, y)));
+        Jacobian::from(point)
+            .mul(&super::fr::MODULUS.m)
+            .is_identity()
+            .then_some(point)
+    }
+
+    /// The coordinates `(x, y)`, or `None` for the point at infinity.
+    pub fn to_xy(&self) -> Option<(Fq2, Fq2)> {
+        self.0
+    }
+
+    /// Returns `true` if this is the point at infinity.
+    pub const fn is_identity(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl Default for G2Affine {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Neg for G2Affine {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|(x, y)| (x, -y)))
+    }
+}
+
+/// A point `(X / Z^2, Y / Z^3)` in Jacobian coordinates, the point at infinity having `Z = 0`.
+///
+/// Only used for the subgroup check, which needs a full scalar multiplication.
+#[derive(Clone, Copy)]
+struct Jacobian {
+    x: Fq2,
+    y: Fq2,
+    z: Fq2,
+}
+
+impl Jacobian {
+    const IDENTITY: Self = Self {
+        x: Fq2::ONE,
+        y: Fq2::ONE,
+        z: Fq2::ZERO,
+    };
+
+    fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    fn double(&self) -> Self {
+        if self.is_identity() || self.y.is_zero() {
+            return Self::IDENTITY;
+        }
+        // dbl-2009-l
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let x = e.square() - d.double();
+        let y = e * (d - x) - c.double().double().double();
+        let z = (self.y * self.z).double();
+        Self { x, y, z }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        if self.is_identity() {
+            return *other;
+        }
+        if other.is_identity() {
+            return *self;
+        }
+        // add-2007-bl
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.double()
+            } else {
+                Self::IDENTITY
+            };
+        }
+        let h = u2 - u1;
+        let i = h.double().square();
+        let j = h * i;
+        let r = (s2 - s1).double();
+        let v = u1 * i;
+        let x = r.square() - j - v.double();
+        let y = r * (v - x) - (s1 * j).double();
+        let z = ((self.z + other.z).square() - z1z1 - z2z2) * h;
+        Self { x, y, z }
+    }
+
+    /// Compute `k * self` for a scalar given as little-endian limbs.
+    fn mul(&self, k: &[u32; 8]) -> Self {
+        let mut acc = Self::IDENTITY;
+        for i in (0..256).rev() {
+            acc = acc.double();
+            if (k[i / 32] >> (i % 32)) & 1 == 1 {
+                acc = acc.add(self);
+            }
+        }
+        acc
+    }
+}
+
+impl From<G2Affine> for Jacobian {
+    fn from(point: G2Affine) -> Self {
+        match point.0 {
+            Some((x, y)) => Self { x, y, z: Fq2::ONE },
+            None => Self::IDENTITY,
+        }
+    }
+}
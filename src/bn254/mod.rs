@@ -0,0 +1,404 @@
+//! bn254 scalar, base field, G1 and pairing operation
+
+#[cfg(feature = "arkworks")]
+pub mod ark;
+#[cfg(feature = "ff")]
+mod ff;
+pub(crate) mod fq;
+mod fq12;
+pub(crate) mod fr;
+pub(crate) mod g1;
+pub(crate) mod g2;
+mod pairing;
+
+pub use fq::{Fq, Fq2};
+pub use fr::Fr;
+pub use g1::G1Affine;
+pub use g2::G2Affine;
+pub use pairing::pairing_check;
+
+use crate::{checked, SyscallCode};
+
+/// `BN254_SCALAR_MUL` syscall ID.
+#[cfg(sp1_syscall = "BN254_SCALAR_MUL")]
+pub const BN254_SCALAR_MUL: u32 = SyscallCode::Bn254ScalarMul.as_u32();
+
+/// `BN254_SCALAR_MAC` syscall ID.
+#[cfg(sp1_syscall = "BN254_SCALAR_MAC")]
+pub const BN254_SCALAR_MAC: u32 = SyscallCode::Bn254ScalarMac.as_u32();
+
+/// `BN254_MULADD` syscall ID.
+///
+/// This code is in none of the pinned SP1 tables under `syscalls/`, and its syscall number
+/// `0x1F` is the one of `BLS12381_DOUBLE`, so it has no [`SyscallCode`] and, like the wrappers
+/// issuing it, is not compiled for any release.
+#[cfg(sp1_syscall = "BN254_MULADD")]
+pub const BN254_MULADD: u32 = 0x00_01_01_1F;
+
+/// `BN254_ADD` syscall ID.
+#[cfg(sp1_syscall = "BN254_ADD")]
+pub const BN254_ADD: u32 = SyscallCode::Bn254Add.as_u32();
+
+/// `BN254_DOUBLE` syscall ID.
+#[cfg(sp1_syscall = "BN254_DOUBLE")]
+pub const BN254_DOUBLE: u32 = SyscallCode::Bn254Double.as_u32();
+
+/// `BN254_FP_ADD` syscall ID.
+#[cfg(sp1_syscall = "BN254_FP_ADD")]
+pub const BN254_FP_ADD: u32 = SyscallCode::Bn254FpAdd.as_u32();
+
+/// `BN254_FP_SUB` syscall ID.
+#[cfg(sp1_syscall = "BN254_FP_SUB")]
+pub const BN254_FP_SUB: u32 = SyscallCode::Bn254FpSub.as_u32();
+
+/// `BN254_FP_MUL` syscall ID.
+#[cfg(sp1_syscall = "BN254_FP_MUL")]
+pub const BN254_FP_MUL: u32 = SyscallCode::Bn254FpMul.as_u32();
+
+/// `BN254_FP2_ADD` syscall ID.
+#[cfg(sp1_syscall = "BN254_FP2_ADD")]
+pub const BN254_FP2_ADD: u32 = SyscallCode::Bn254Fp2Add.as_u32();
+
+/// `BN254_FP2_SUB` syscall ID.
+#[cfg(sp1_syscall = "BN254_FP2_SUB")]
+pub const BN254_FP2_SUB: u32 = SyscallCode::Bn254Fp2Sub.as_u32();
+
+/// `BN254_FP2_MUL` syscall ID.
+#[cfg(sp1_syscall = "BN254_FP2_MUL")]
+pub const BN254_FP2_MUL: u32 = SyscallCode::Bn254Fp2Mul.as_u32();
+
+/// Perform in-place scalar multiplication `p *= q`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for writes of [`Fr`], and must remain valid even
+///   when `q` is read for [`Fr`].
+///
+/// * `q` must be [valid] for reads of [`Fr`].
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_SCALAR_MUL")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_scalar_mul<P, Q>(p: *mut P, q: *const Q) {
+    unsafe {
+        checked::binary(
+            p as *const [u32; 8],
+            q as *const [u32; 8],
+            Some(&fr::MODULUS),
+        );
+        crate::syscall!(BN254_SCALAR_MUL, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place scalar multiplication and addition `ret += a * b`.
+///
+/// The syscall takes `ret` in `a0` and, in `a1`, a pointer to the operand pointers `[a, b]`
+/// stored as two consecutive words.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `ret` must be [valid] for writes of [`Fr`], and must remain valid even
+///   when `a` and `b` are read for [`Fr`].
+///
+/// * `a` and `b` must be [valid] for reads of [`Fr`].
+///
+/// * Both `ret`, `a`, and `b` must be properly aligned, and `ret` must not overlap `a` or `b`.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_SCALAR_MAC")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_scalar_mac<R, T>(ret: *mut R, a: *const T, b: *const T) {
+    // The operand pointers must stay alive in memory until the syscall has read them.
+    let operands: [*const T; 2] = [a, b];
+    checked::pointer("ret", ret);
+    checked::pointer("a", a);
+    checked::pointer("b", b);
+    checked::disjoint(("ret", "a"), ret.cast::<[u32; 8]>(), a.cast::<[u32; 8]>());
+    checked::disjoint(("ret", "b"), ret.cast::<[u32; 8]>(), b.cast::<[u32; 8]>());
+    unsafe {
+        checked::canonical("ret", ret as *const [u32; 8], &fr::MODULUS);
+        checked::canonical("a", a as *const [u32; 8], &fr::MODULUS);
+        checked::canonical("b", b as *const [u32; 8], &fr::MODULUS);
+        crate::syscall!(BN254_SCALAR_MAC, ret, operands.as_ptr(); options(nostack))
+    }
+}
+
+/// Perform in-place multiplication and addition `x += y[0] * y[1]`,
+/// where `y` holds the two operands back to back.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `x` must be [valid] for reads and writes of `[u32; 8]`.
+///
+/// * `y` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `x` and `y` must be properly aligned and not overlap.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_MULADD")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_muladd(x: *mut [u32; 8], y: *const [u32; 8]) {
+    checked::pointer("x", x);
+    checked::pointer("y", y);
+    checked::disjoint(("x", "y"), x, y as *const [u32; 16]);
+    unsafe { crate::syscall!(BN254_MULADD, x, y; options(nostack)) }
+}
+
+/// Perform in-place G1 point addition `p += q`, over affine `x || y` points of 8
+/// little-endian limbs per coordinate.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`, and must remain valid even
+///   when `q` is read for `[u32; 16]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be points on the curve with canonical coordinates, and `p != ±q`.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_ADD")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        checked::binary(p, q, Some(&fq::MODULUS));
+        crate::syscall!(BN254_ADD, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place G1 point doubling `p = 2 * p`, over an affine `x || y` point of 8
+/// little-endian limbs per coordinate.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`.
+///
+/// * `p` must be properly aligned.
+///
+/// * `p` must be a point on the curve with canonical coordinates.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_DOUBLE")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_double(p: *mut [u32; 16]) {
+    unsafe {
+        checked::unary(p, Some(&fq::MODULUS));
+        crate::syscall!(BN254_DOUBLE, p, 0; options(nostack))
+    }
+}
+
+/// Perform in-place base field addition `p = p + q`, over 8 little-endian limbs.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 8]`, and must remain valid even
+///   when `q` is read for `[u32; 8]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 8]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be canonical, i.e. less than the base field modulus.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_FP_ADD")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_fp_addmod(p: *mut [u32; 8], q: *const [u32; 8]) {
+    unsafe {
+        checked::binary(p, q, Some(&fq::MODULUS));
+        crate::syscall!(BN254_FP_ADD, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place base field subtraction `p = p - q`, over 8 little-endian limbs.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 8]`, and must remain valid even
+///   when `q` is read for `[u32; 8]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 8]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be canonical, i.e. less than the base field modulus.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_FP_SUB")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_fp_submod(p: *mut [u32; 8], q: *const [u32; 8]) {
+    unsafe {
+        checked::binary(p, q, Some(&fq::MODULUS));
+        crate::syscall!(BN254_FP_SUB, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place base field multiplication `p = p * q`, over 8 little-endian limbs.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 8]`, and must remain valid even
+///   when `q` is read for `[u32; 8]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 8]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be canonical, i.e. less than the base field modulus.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_FP_MUL")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_fp_mulmod(p: *mut [u32; 8], q: *const [u32; 8]) {
+    unsafe {
+        checked::binary(p, q, Some(&fq::MODULUS));
+        crate::syscall!(BN254_FP_MUL, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place quadratic extension field addition `p = p + q`, over `c0 || c1`
+/// elements of 8 little-endian limbs per coefficient.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`, and must remain valid even
+///   when `q` is read for `[u32; 16]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Every coefficient of `p` and `q` must be canonical, i.e. less than the base field modulus.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_FP2_ADD")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_fp2_addmod(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        checked::binary(p, q, Some(&fq::MODULUS));
+        crate::syscall!(BN254_FP2_ADD, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place quadratic extension field subtraction `p = p - q`, over `c0 || c1`
+/// elements of 8 little-endian limbs per coefficient.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`, and must remain valid even
+///   when `q` is read for `[u32; 16]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Every coefficient of `p` and `q` must be canonical, i.e. less than the base field modulus.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_FP2_SUB")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_fp2_submod(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        checked::binary(p, q, Some(&fq::MODULUS));
+        crate::syscall!(BN254_FP2_SUB, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place quadratic extension field multiplication `p = p * q`, over `c0 || c1`
+/// elements of 8 little-endian limbs per coefficient.
+///
+/// The extension is `Fq2 = Fq[u] / (u^2 + 1)`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`, and must remain valid even
+///   when `q` is read for `[u32; 16]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Every coefficient of `p` and `q` must be canonical, i.e. less than the base field modulus.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[cfg(sp1_syscall = "BN254_FP2_MUL")]
+#[inline(always)]
+pub unsafe fn syscall_bn254_fp2_mulmod(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        checked::binary(p, q, Some(&fq::MODULUS));
+        crate::syscall!(BN254_FP2_MUL, p, q; options(nostack))
+    }
+}
+
+/// The number of limbs in a "uint256".
+#[cfg(sp1_syscall = "BN254_MULADD")]
+const N: usize = 8;
+
+#[cfg(sp1_syscall = "BN254_MULADD")]
+#[allow(unused_variables)]
+pub fn syscall_bn254_muladd_entrypoint(
+    result: *mut [u32; N],
+    op: u32,
+    x: *const [u32; N],
+    y: *const [u32; N],
+    z: *const [u32; N],
+) {
+    // Instantiate a new uninitialized array of words to place the concatenated y and z.
+    let mut concat_y_z = core::mem::MaybeUninit::<[u32; N * 2]>::uninit();
+    unsafe {
+        let result_ptr = result as *mut u32;
+        let x_ptr = x as *const u32;
+        let y_ptr = y as *const u32;
+        let concat_ptr = concat_y_z.as_mut_ptr() as *mut u32;
+
+        // First copy the x value into the concatenated array.
+        core::ptr::copy(x_ptr, concat_ptr, N);
+
+        // Then, copy the y value into the concatenated array. Add the width of the y value
+        // to the pointer to place the z value after the y value.
+        core::ptr::copy(y as *const u32, concat_ptr.add(N), N);
+
+        // Copy z into the result array, as our syscall will write the result into the first input.
+        core::ptr::copy(z as *const u32, result_ptr, N);
+
+        // Call the uint256_muladd syscall to multiply the x value with the concatenated y and z.
+        // This syscall writes the result in-place, so it will mutate the result ptr appropriately.
+        let result_ptr = result_ptr as *mut [u32; N];
+        let concat_ptr = concat_ptr as *mut [u32; N];
+        syscall_bn254_muladd(result_ptr, concat_ptr);
+    }
+}
@@ -0,0 +1,116 @@
+//! Runtime checks of the `# Safety` contracts of the syscall wrappers.
+//!
+//! The checks only run with the `checked` feature, and compile to nothing without it. A
+//! violated contract then panics with a message naming the argument, before the `ecall` is
+//! issued, instead of producing a trace that cannot be proven.
+//!
+//! [`pointer`] and [`disjoint`] are public for the wrappers generated by `#[sp1_syscall]`, see
+//! `__checked` in the crate root.
+
+use core::cmp::Ordering;
+
+use crate::arith::{self, Modulus};
+
+/// Check that `p` is non-null and aligned to 4 bytes.
+#[inline(always)]
+#[track_caller]
+pub fn pointer<T>(name: &str, p: *const T) {
+    if cfg!(feature = "checked") {
+        assert!(!p.is_null(), "`{name}` is null");
+        assert!(
+            (p as usize).is_multiple_of(4),
+            "`{name}` is not aligned to 4 bytes: {p:p}"
+        );
+    }
+}
+
+/// Check that the `T` at `a` and the `U` at `b` do not overlap.
+#[inline(always)]
+#[track_caller]
+pub fn disjoint<T, U>(names: (&str, &str), a: *const T, b: *const U) {
+    if cfg!(feature = "checked") {
+        let (a, b) = (a as usize, b as usize);
+        let (a_end, b_end) = (a + size_of::<T>(), b + size_of::<U>());
+        assert!(
+            a_end <= b || b_end <= a,
+            "`{}` and `{}` overlap: {a:#x}..{a_end:#x} and {b:#x}..{b_end:#x}",
+            names.0,
+            names.1,
+        );
+    }
+}
+
+/// Check that every `N`-limb element of `p`, e.g. every coordinate of a point, is less than
+/// `modulus`.
+///
+/// # Safety
+///
+/// `p` must be [valid] for reads of `[u32; L]`, as the wrappers calling this require anyway.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+#[track_caller]
+pub(crate) unsafe fn canonical<const L: usize, const N: usize>(
+    name: &str,
+    p: *const [u32; L],
+    modulus: &Modulus<N>,
+) {
+    if cfg!(feature = "checked") {
+        let limbs = unsafe { &*p };
+        for (i, element) in limbs.chunks_exact(N).enumerate() {
+            let element: &[u32; N] = element.try_into().unwrap();
+            assert!(
+                arith::cmp(element, &modulus.m) == Ordering::Less,
+                "`{name}` is not canonical: limbs {}..{} are not less than the modulus",
+                N * i,
+                N * i + N,
+            );
+        }
+    }
+}
+
+/// Check the operand of an in-place unary syscall `p = op(p)`: [`pointer`] and, given the
+/// modulus of its elements, [`canonical`].
+///
+/// # Safety
+///
+/// `p` must be [valid] for reads of `[u32; L]` if it is non-null and aligned.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+#[track_caller]
+pub(crate) unsafe fn unary<const L: usize, const N: usize>(
+    p: *const [u32; L],
+    modulus: Option<&Modulus<N>>,
+) {
+    pointer("p", p);
+    if let Some(modulus) = modulus {
+        unsafe { canonical("p", p, modulus) };
+    }
+}
+
+/// Check the operands of an in-place binary syscall `p = op(p, q)`: [`pointer`] and
+/// [`disjoint`] and, given the modulus of their elements, [`canonical`].
+///
+/// # Safety
+///
+/// `p` and `q` must be [valid] for reads of `[u32; L]` if they are non-null and aligned.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+#[track_caller]
+pub(crate) unsafe fn binary<const L: usize, const N: usize>(
+    p: *const [u32; L],
+    q: *const [u32; L],
+    modulus: Option<&Modulus<N>>,
+) {
+    pointer("p", p);
+    pointer("q", q);
+    disjoint(("p", "q"), p, q);
+    if let Some(modulus) = modulus {
+        unsafe {
+            canonical("p", p, modulus);
+            canonical("q", q, modulus);
+        }
+    }
+}
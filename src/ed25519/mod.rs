@@ -0,0 +1,231 @@
+//! Ed25519 curve operations and signature verification.
+//!
+//! Points of the twisted Edwards curve are affine `x || y`, each coordinate 8 little-endian
+//! `u32` limbs. The addition law is complete, so the identity `(0, 1)` and doubling need no
+//! special handling.
+
+use core::cmp::Ordering;
+
+use crate::arith::{self, Modulus};
+use crate::{checked, SyscallCode};
+
+mod sha512;
+
+use sha512::Sha512;
+
+/// `ED_ADD` syscall ID.
+pub const ED_ADD: u32 = SyscallCode::EdAdd.as_u32();
+
+/// `ED_DECOMPRESS` syscall ID.
+pub const ED_DECOMPRESS: u32 = SyscallCode::EdDecompress.as_u32();
+
+/// Perform in-place point addition `p += q`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`, and must remain valid even
+///   when `q` is read for `[u32; 16]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be points on the curve with canonical coordinates.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_ed_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        checked::binary(p, q, Some(&FIELD));
+        crate::syscall!(ED_ADD, p, q; options(nostack))
+    }
+}
+
+/// Decompress a point from its 32-byte encoding.
+///
+/// `point[32..64]` holds the standard encoding: `y` as little-endian bytes, with the sign of
+/// `x` in the top bit. The syscall writes `x` to `point[0..32]`, as little-endian bytes.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `point` must be [valid] for reads and writes of `[u8; 64]`.
+///
+/// * `point` must be aligned to 4 bytes.
+///
+/// * The encoding must be that of a point on the curve, with `y < p`.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_ed_decompress(point: *mut [u8; 64]) {
+    checked::pointer("point", point);
+    unsafe { crate::syscall!(ED_DECOMPRESS, point, 0; options(nostack)) }
+}
+
+/// The base field modulus `p = 2^255 - 19`.
+pub(crate) const FIELD: Modulus<8> = Modulus::new([
+    0xffffffed, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+]);
+
+/// The order `L` of the prime-order subgroup.
+const ORDER: Modulus<8> = Modulus::new([
+    0x5cf5d3ed, 0x5812631a, 0xa2f79cd6, 0x14def9de, 0x00000000, 0x00000000, 0x00000000, 0x10000000,
+]);
+
+/// The curve constant `d = -121665 / 121666`.
+pub(crate) const D: [u32; 8] = [
+    0x135978a3, 0x75eb4dca, 0x4141d8ab, 0x00700a4d, 0x7779e898, 0x8cc74079, 0x2b6ffe73, 0x52036cee,
+];
+
+/// The base point `B`.
+const BASE: [u32; 16] = [
+    0x8f25d51a, 0xc9562d60, 0x9525a7b2, 0x692cc760, 0xfdd6dc5c, 0xc0a4e231, 0xcd6e53fe, 0x216936d3,
+    0x66666658, 0x66666666, 0x66666666, 0x66666666, 0x66666666, 0x66666666, 0x66666666, 0x66666666,
+];
+
+/// The identity `(0, 1)`.
+const IDENTITY: [u32; 16] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+
+/// Verify an Ed25519 signature `sig = R || S` of `msg` by the public key `pubkey`.
+///
+/// Verification is cofactored, checking `[8][S]B == [8]R + [8][k]A` for
+/// `k = SHA-512(R || A || msg)`. Non-canonical encodings of `A` and `R`, `S >= L`, and
+/// public keys or `R` of small order are rejected.
+///
+/// This is safe to call: every encoding is checked to decompress to a curve point before
+/// reaching the decompress syscall, and only curve points are passed to the add syscall.
+pub fn verify(pubkey: &[u8; 32], msg: &[u8], sig: &[u8; 64]) -> bool {
+    let (Some(a), Some(r)) = (
+        decompress(pubkey),
+        decompress(sig[..32].try_into().unwrap()),
+    ) else {
+        return false;
+    };
+    if is_small_order(&a) || is_small_order(&r) {
+        return false;
+    }
+    let s = from_le_bytes(&sig[32..]);
+    if arith::cmp(&s, &ORDER.m) != Ordering::Less {
+        return false;
+    }
+
+    let mut hasher = Sha512::new();
+    hasher.update(&sig[..32]);
+    hasher.update(pubkey);
+    hasher.update(msg);
+    let digest = hasher.finalize();
+    let k = ORDER.reduce_wide(&from_le_bytes(&digest[..32]), &from_le_bytes(&digest[32..]));
+
+    // [8]([S]B - [k]A - R) must be the identity.
+    let mut p = mul_add(&s, &BASE, &k, &neg(&a));
+    add(&mut p, &neg(&r));
+    for _ in 0..3 {
+        double(&mut p);
+    }
+    p == IDENTITY
+}
+
+/// Decode a point, returning `None` unless `bytes` is the canonical encoding of a curve point.
+fn decompress(bytes: &[u8; 32]) -> Option<[u32; 16]> {
+    let mut y = from_le_bytes(bytes);
+    let sign = y[7] >> 31;
+    y[7] &= 0x7fff_ffff;
+    if arith::cmp(&y, &FIELD.m) != Ordering::Less {
+        return None;
+    }
+    let mut point = [0u32; 16];
+    point[8..].copy_from_slice(&y);
+
+    // x^2 = (y^2 - 1) / (d * y^2 + 1), where the denominator never vanishes.
+    let yy = FIELD.mul(&y, &y);
+    let u = FIELD.sub(&yy, &arith::one());
+    let v = FIELD.add(&FIELD.mul(&D, &yy), &arith::one());
+    if arith::is_zero(&u) {
+        // x = 0 has no negative encoding.
+        return (sign == 0).then_some(point);
+    }
+    // The decompress syscall cannot be proven for an encoding off the curve, so check first.
+    if !FIELD.is_square(&FIELD.mul(&u, &FIELD.inv(&v))) {
+        return None;
+    }
+
+    let mut buf = [0u32; 16];
+    for (word, chunk) in buf[8..].iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_ne_bytes(chunk.try_into().unwrap());
+    }
+    // SAFETY: `buf` is a local 64-byte buffer aligned to 4 bytes, holding the encoding of a
+    // curve point as checked above.
+    unsafe { syscall_ed_decompress(buf.as_mut_ptr() as *mut [u8; 64]) };
+    for (limb, word) in point[..8].iter_mut().zip(&buf[..8]) {
+        *limb = u32::from_le(*word);
+    }
+    Some(point)
+}
+
+/// Compute `p += q` for curve points.
+fn add(p: &mut [u32; 16], q: &[u32; 16]) {
+    // SAFETY: references are valid, aligned and cannot overlap, and every point handled by
+    // this module lies on the curve.
+    unsafe { syscall_ed_add(p, q) }
+}
+
+/// Compute `p = 2 * p` for a curve point.
+fn double(p: &mut [u32; 16]) {
+    let q = *p;
+    add(p, &q);
+}
+
+/// Compute `-p` for a curve point.
+fn neg(p: &[u32; 16]) -> [u32; 16] {
+    let mut r = *p;
+    let x = FIELD.neg(p[..8].try_into().unwrap());
+    r[..8].copy_from_slice(&x);
+    r
+}
+
+/// Returns `true` if `[8]p` is the identity.
+fn is_small_order(p: &[u32; 16]) -> bool {
+    let mut p = *p;
+    for _ in 0..3 {
+        double(&mut p);
+    }
+    p == IDENTITY
+}
+
+/// Compute `a * p + b * q` for scalars given as little-endian limbs.
+///
+/// Both products share one pass of doublings over 4-bit windows, each window adding a
+/// precomputed multiple of `p` and of `q`.
+fn mul_add(a: &[u32; 8], p: &[u32; 16], b: &[u32; 8], q: &[u32; 16]) -> [u32; 16] {
+    let (p_table, q_table) = (table(p), table(q));
+    let digit = |k: &[u32; 8], i: usize| (k[i / 8] >> (4 * (i % 8))) as usize & 0xf;
+    let mut acc = IDENTITY;
+    for i in (0..64).rev() {
+        for _ in 0..4 {
+            double(&mut acc);
+        }
+        add(&mut acc, &p_table[digit(a, i)]);
+        add(&mut acc, &q_table[digit(b, i)]);
+    }
+    acc
+}
+
+/// The multiples `[0 * p, 1 * p, ..., 15 * p]`.
+fn table(p: &[u32; 16]) -> [[u32; 16]; 16] {
+    let mut table = [IDENTITY; 16];
+    for i in 1..16 {
+        table[i] = table[i - 1];
+        add(&mut table[i], p);
+    }
+    table
+}
+
+/// Read 8 little-endian limbs from 32 bytes.
+fn from_le_bytes(bytes: &[u8]) -> [u32; 8] {
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
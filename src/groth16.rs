@@ -0,0 +1,342 @@
+//! Groth16 proof verification over bn254.
+//!
+//! The public input linear combination runs on the G1 curve syscalls, not on the scalar ones,
+//! and the final equation on [`pairing_check`]. Keys and proofs can be read from the encodings of gnark and arkworks.
+
+use crate::arith;
+use crate::bn254::{g2, pairing_check, Fq, Fq2, Fr, G1Affine, G2Affine};
+
+/// A Groth16 verifying key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    /// `[alpha]_1`.
+    pub alpha_g1: G1Affine,
+    /// `[beta]_2`.
+    pub beta_g2: G2Affine,
+    /// `[gamma]_2`.
+    pub gamma_g2: G2Affine,
+    /// `[delta]_2`.
+    pub delta_g2: G2Affine,
+    /// The bases of the public input linear combination, one more than the number of public
+    /// inputs. Called `K` by gnark and `gamma_abc_g1` by arkworks.
+    pub ic: Vec<G1Affine>,
+}
+
+/// A Groth16 proof `(A, B, C)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Proof {
+    /// `[A]_1`, `Ar` in gnark.
+    pub a: G1Affine,
+    /// `[B]_2`, `Bs` in gnark.
+    pub b: G2Affine,
+    /// `[C]_1`, `Krs` in gnark.
+    pub c: G1Affine,
+}
+
+impl VerifyingKey {
+    /// Read a verifying key as written by gnark's `WriteTo` (compressed points) or
+    /// `WriteRawTo` (uncompressed points), returning `None` if the encoding is invalid.
+    ///
+    /// Trailing bytes, such as the commitment keys of recent gnark versions, are ignored;
+    /// circuits with Pedersen commitments are not supported.
+    pub fn from_gnark_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader::gnark(bytes)?;
+        let alpha_g1 = reader.g1()?;
+        let _beta_g1 = reader.g1()?;
+        let beta_g2 = reader.g2()?;
+        let gamma_g2 = reader.g2()?;
+        let _delta_g1 = reader.g1()?;
+        let delta_g2 = reader.g2()?;
+        let len = u32::from_be_bytes(reader.take(4)?.try_into().unwrap());
+        let ic = (0..len).map(|_| reader.g1()).collect::<Option<_>>()?;
+        Some(Self {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            ic,
+        })
+    }
+
+    /// Read a verifying key serialized with arkworks' `CanonicalSerialize`, compressed or not,
+    /// returning `None` if the encoding is invalid.
+    pub fn from_arkworks_bytes(bytes: &[u8]) -> Option<Self> {
+        // The header is `alpha_g1, beta_g2, gamma_g2, delta_g2` and the length of `ic`, which
+        // tells the two point sizes apart.
+        let compressed = [(true, 224, 32), (false, 448, 64)]
+            .into_iter()
+            .find(|&(_, header, point)| {
+                let len = bytes
+                    .get(header..header + 8)
+                    .map(|len| u64::from_le_bytes(len.try_into().unwrap()));
+                len.and_then(|len| len.checked_mul(point)?.checked_add(header as u64 + 8))
+                    == Some(bytes.len() as u64)
+            })?
+            .0;
+        let mut reader = Reader {
+            bytes,
+            encoding: Encoding::Arkworks,
+            compressed,
+        };
+        let alpha_g1 = reader.g1()?;
+        let beta_g2 = reader.g2()?;
+        let gamma_g2 = reader.g2()?;
+        let delta_g2 = reader.g2()?;
+        let len = u64::from_le_bytes(reader.take(8)?.try_into().unwrap());
+        let ic = (0..len).map(|_| reader.g1()).collect::<Option<_>>()?;
+        Some(Self {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            ic,
+        })
+    }
+}
+
+impl Proof {
+    /// Read a proof as written by gnark's `WriteTo` (compressed points) or `WriteRawTo`
+    /// (uncompressed points), returning `None` if the encoding is invalid.
+    ///
+    /// Trailing bytes, such as the commitments of recent gnark versions, are ignored; circuits
+    /// with Pedersen commitments are not supported.
+    pub fn from_gnark_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader::gnark(bytes)?;
+        Some(Self {
+            a: reader.g1()?,
+            b: reader.g2()?,
+            c: reader.g1()?,
+        })
+    }
+
+    /// Read a proof serialized with arkworks' `CanonicalSerialize`, compressed (128 bytes) or
+    /// not (256 bytes), returning `None` if the encoding is invalid.
+    pub fn from_arkworks_bytes(bytes: &[u8]) -> Option<Self> {
+        let compressed = match bytes.len() {
+            128 => true,
+            256 => false,
+            _ => return None,
+        };
+        let mut reader = Reader {
+            bytes,
+            encoding: Encoding::Arkworks,
+            compressed,
+        };
+        Some(Self {
+            a: reader.g1()?,
+            b: reader.g2()?,
+            c: reader.g1()?,
+        })
+    }
+}
+
+/// Returns `true` if `proof` is valid for `public_inputs` under `vk`.
+///
+/// Checks `e(A, B) = e(alpha, beta) * e(IC_0 + sum_i x_i * IC_(i + 1), gamma) * e(C, delta)`.
+/// A number of public inputs not matching the key is rejected.
+///
+/// The input linear combination multiplies G1 points by scalars, so it runs on the
+/// `BN254_ADD` and `BN254_DOUBLE` curve syscalls: the `BN254_SCALAR_MUL` and `BN254_SCALAR_MAC`
+/// syscalls multiply two scalars and have no use in it.
+pub fn verify(vk: &VerifyingKey, proof: &Proof, public_inputs: &[Fr]) -> bool {
+    let Some((ic_0, ic)) = vk.ic.split_first() else {
+        return false;
+    };
+    if ic.len() != public_inputs.len() {
+        return false;
+    }
+    let acc = ic
+        .iter()
+        .zip(public_inputs)
+        .fold(*ic_0, |acc, (base, x)| acc + *base * x);
+    pairing_check(&[
+        (-proof.a, proof.b),
+        (vk.alpha_g1, vk.beta_g2),
+        (acc, vk.gamma_g2),
+        (proof.c, vk.delta_g2),
+    ])
+}
+
+/// The point encoding of a library.
+#[derive(Clone, Copy)]
+enum Encoding {
+    /// Big-endian coordinates, `c1` before `c0`, with the flags in the top bits of the first
+    /// byte.
+    Gnark,
+    /// Little-endian coordinates, `c0` before `c1`, with the flags in the top bits of the last
+    /// byte.
+    Arkworks,
+}
+
+/// A cursor over a sequence of encoded points.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    encoding: Encoding,
+    compressed: bool,
+}
+
+impl<'a> Reader<'a> {
+    /// gnark flags every point, so a compressed first point tells the encoding apart.
+    fn gnark(bytes: &'a [u8]) -> Option<Self> {
+        let flags = bytes.first()? & FLAG_MASK;
+        Some(Self {
+            bytes,
+            encoding: Encoding::Gnark,
+            compressed: flags == GNARK_SMALLEST || flags == GNARK_LARGEST,
+        })
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    fn g1(&mut self) -> Option<G1Affine> {
+        if self.compressed {
+            let ([x], flags) = read_coords::<1>(self.encoding, self.take(32)?)?;
+            match self.decode_flags(flags)? {
+                Flags::Infinity => Some(G1Affine::IDENTITY),
+                Flags::Point { largest } => {
+                    let y = (x.square() * x + Fq::from(3)).sqrt()?;
+                    let y = if is_largest(&y) == largest { y } else { -y };
+                    G1Affine::new(x.0, y.0)
+                }
+            }
+        } else {
+            let ([x, y], flags) = read_coords::<2>(self.encoding, self.take(64)?)?;
+            match self.decode_flags(flags)? {
+                Flags::Infinity => Some(G1Affine::IDENTITY),
+                Flags::Point { .. } if x.is_zero() && y.is_zero() => Some(G1Affine::IDENTITY),
+                Flags::Point { .. } => G1Affine::new(x.0, y.0),
+            }
+        }
+    }
+
+    fn g2(&mut self) -> Option<G2Affine> {
+        // Coefficients are read in encoding order, `c1, c0` for gnark and `c0, c1` otherwise.
+        let encoding = self.encoding;
+        let fq2 = |a: Fq, b: Fq| match encoding {
+            Encoding::Gnark => Fq2::new(b, a),
+            Encoding::Arkworks => Fq2::new(a, b),
+        };
+        if self.compressed {
+            let ([x0, x1], flags) = read_coords::<2>(encoding, self.take(64)?)?;
+            match self.decode_flags(flags)? {
+                Flags::Infinity => Some(G2Affine::IDENTITY),
+                Flags::Point { largest } => {
+                    let x = fq2(x0, x1);
+                    let y = (x.square() * x + g2::B).sqrt()?;
+                    let y = if is_largest_fq2(encoding, &y) == largest {
+                        y
+                    } else {
+                        -y
+                    };
+                    G2Affine::new(x, y)
+                }
+            }
+        } else {
+            let (xy, flags) = read_coords::<4>(encoding, self.take(128)?)?;
+            match self.decode_flags(flags)? {
+                Flags::Infinity => Some(G2Affine::IDENTITY),
+                Flags::Point { .. } if xy.iter().all(Fq::is_zero) => Some(G2Affine::IDENTITY),
+                Flags::Point { .. } => G2Affine::new(fq2(xy[0], xy[1]), fq2(xy[2], xy[3])),
+            }
+        }
+    }
+
+    /// Decode the flag bits of a point, returning `None` for a combination the encoding
+    /// forbids.
+    fn decode_flags(&self, flags: u8) -> Option<Flags> {
+        match (self.encoding, flags) {
+            (_, INFINITY) => Some(Flags::Infinity),
+            (Encoding::Gnark, GNARK_SMALLEST) if self.compressed => {
+                Some(Flags::Point { largest: false })
+            }
+            (Encoding::Gnark, GNARK_LARGEST) if self.compressed => {
+                Some(Flags::Point { largest: true })
+            }
+            (Encoding::Gnark, 0) if !self.compressed => Some(Flags::Point { largest: false }),
+            // arkworks sets the sign of `y` on uncompressed points too.
+            (Encoding::Arkworks, ARKWORKS_Y_IS_NEGATIVE) => Some(Flags::Point { largest: true }),
+            (Encoding::Arkworks, 0) => Some(Flags::Point { largest: false }),
+            _ => None,
+        }
+    }
+}
+
+/// The two flag bits, at the top of the first (gnark) or last (arkworks) byte of a point.
+const FLAG_MASK: u8 = 0b11 << 6;
+
+/// gnark's `mCompressedSmallest`.
+const GNARK_SMALLEST: u8 = 0b10 << 6;
+
+/// gnark's `mCompressedLargest`.
+const GNARK_LARGEST: u8 = 0b11 << 6;
+
+/// gnark's `mCompressedInfinity` and `mUncompressedInfinity`, and arkworks' `PointAtInfinity`.
+const INFINITY: u8 = 0b01 << 6;
+
+/// arkworks' `YIsNegative`, set when `y` is the lexicographically largest root.
+const ARKWORKS_Y_IS_NEGATIVE: u8 = 0b10 << 6;
+
+/// The decoded flags of a point.
+enum Flags {
+    Infinity,
+    /// Whether `y` is the lexicographically largest root matters for compressed points only.
+    Point {
+        largest: bool,
+    },
+}
+
+/// Read `N` consecutive coordinates of 32 bytes, returning them with the flag bits cleared
+/// and the flags themselves, or `None` if a coordinate is not canonical.
+fn read_coords<const N: usize>(encoding: Encoding, bytes: &[u8]) -> Option<([Fq; N], u8)> {
+    let mut bytes: [[u8; 32]; N] =
+        core::array::from_fn(|i| bytes[32 * i..32 * (i + 1)].try_into().unwrap());
+    let flag_byte = match encoding {
+        Encoding::Gnark => &mut bytes[0][0],
+        Encoding::Arkworks => &mut bytes[N - 1][31],
+    };
+    let flags = *flag_byte & FLAG_MASK;
+    *flag_byte &= !FLAG_MASK;
+
+    let mut coords = [Fq::ZERO; N];
+    for (coord, bytes) in coords.iter_mut().zip(&bytes) {
+        *coord = match encoding {
+            Encoding::Gnark => Fq::from_limbs(arith::from_be_bytes(bytes))?,
+            Encoding::Arkworks => Fq::from_bytes(bytes)?,
+        };
+    }
+    Some((coords, flags))
+}
+
+/// Returns `true` if `y > -y` as integers, i.e. `y > (q - 1) / 2` for `y != 0`.
+///
+/// This is gnark-crypto's `fp.Element.LexicographicallyLargest` and the `Ord` of arkworks'
+/// `Fp`.
+fn is_largest(y: &Fq) -> bool {
+    arith::cmp(&y.0, &(-*y).0).is_gt()
+}
+
+/// Returns `true` if `y > -y` in the order of `Fq2` the compression flag of `encoding` refers
+/// to.
+///
+/// The coefficient compared first decides which root a compressed point designates, so it is
+/// spelled out per library even though both agree today.
+fn is_largest_fq2(encoding: Encoding, y: &Fq2) -> bool {
+    let (first, second) = match encoding {
+        // gnark-crypto `bn254.E2.LexicographicallyLargest`: `A1`, then `A0` if `A1` is zero.
+        Encoding::Gnark => (y.c1(), y.c0()),
+        // arkworks `QuadExtField`'s `Ord`: `c1`, then `c0` if the `c1` are equal, which for
+        // `y` and `-y` means `c1` is zero.
+        Encoding::Arkworks => (y.c1(), y.c0()),
+    };
+    match first.is_zero() {
+        true => is_largest(&second),
+        false => is_largest(&first),
+    }
+}
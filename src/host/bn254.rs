@@ -0,0 +1,103 @@
+//! Host implementation of the bn254 scalar and base field syscalls.
+
+#[cfg(any(
+    sp1_syscall = "BN254_FP_ADD",
+    sp1_syscall = "BN254_FP_SUB",
+    sp1_syscall = "BN254_FP_MUL",
+    sp1_syscall = "BN254_FP2_ADD",
+    sp1_syscall = "BN254_FP2_SUB",
+    sp1_syscall = "BN254_FP2_MUL",
+))]
+use crate::bn254::fq::MODULUS as Q;
+#[cfg(any(sp1_syscall = "BN254_SCALAR_MUL", sp1_syscall = "BN254_SCALAR_MAC"))]
+use crate::bn254::fr::MODULUS as R;
+
+/// `*p = *p * *q mod r`.
+#[cfg(sp1_syscall = "BN254_SCALAR_MUL")]
+pub(super) unsafe fn scalar_mul(p: *mut [u32; 8], q: *const [u32; 8]) {
+    unsafe {
+        let (a, b) = (R.reduce(&*p), R.reduce(&*q));
+        *p = R.mul(&a, &b);
+    }
+}
+
+/// `*ret = *ret + *a * *b mod r`, where `ab` points to the operand pointers `[a, b]`.
+#[cfg(sp1_syscall = "BN254_SCALAR_MAC")]
+pub(super) unsafe fn scalar_mac(ret: *mut [u32; 8], ab: *const [*const [u32; 8]; 2]) {
+    unsafe {
+        let [a, b] = *ab;
+        let product = R.mul(&R.reduce(&*a), &R.reduce(&*b));
+        *ret = R.add(&R.reduce(&*ret), &product);
+    }
+}
+
+/// `*p = *p + *q mod q`.
+#[cfg(sp1_syscall = "BN254_FP_ADD")]
+pub(super) unsafe fn fp_add(p: *mut [u32; 8], q: *const [u32; 8]) {
+    unsafe { *p = Q.add(&Q.reduce(&*p), &Q.reduce(&*q)) }
+}
+
+/// `*p = *p - *q mod q`.
+#[cfg(sp1_syscall = "BN254_FP_SUB")]
+pub(super) unsafe fn fp_sub(p: *mut [u32; 8], q: *const [u32; 8]) {
+    unsafe { *p = Q.sub(&Q.reduce(&*p), &Q.reduce(&*q)) }
+}
+
+/// `*p = *p * *q mod q`.
+#[cfg(sp1_syscall = "BN254_FP_MUL")]
+pub(super) unsafe fn fp_mul(p: *mut [u32; 8], q: *const [u32; 8]) {
+    unsafe { *p = Q.mul(&Q.reduce(&*p), &Q.reduce(&*q)) }
+}
+
+/// `*p = *p + *q` in `Fq2`, both stored as `c0 || c1`.
+#[cfg(sp1_syscall = "BN254_FP2_ADD")]
+pub(super) unsafe fn fp2_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        let ([a0, a1], [b0, b1]) = (read_fp2(p), read_fp2(q));
+        write_fp2(p, &Q.add(&a0, &b0), &Q.add(&a1, &b1));
+    }
+}
+
+/// `*p = *p - *q` in `Fq2`, both stored as `c0 || c1`.
+#[cfg(sp1_syscall = "BN254_FP2_SUB")]
+pub(super) unsafe fn fp2_sub(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        let ([a0, a1], [b0, b1]) = (read_fp2(p), read_fp2(q));
+        write_fp2(p, &Q.sub(&a0, &b0), &Q.sub(&a1, &b1));
+    }
+}
+
+/// `*p = *p * *q` in `Fq2 = Fq[u] / (u^2 + 1)`, both stored as `c0 || c1`.
+#[cfg(sp1_syscall = "BN254_FP2_MUL")]
+pub(super) unsafe fn fp2_mul(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        let ([a0, a1], [b0, b1]) = (read_fp2(p), read_fp2(q));
+        let c0 = Q.sub(&Q.mul(&a0, &b0), &Q.mul(&a1, &b1));
+        let c1 = Q.add(&Q.mul(&a0, &b1), &Q.mul(&a1, &b0));
+        write_fp2(p, &c0, &c1);
+    }
+}
+
+/// Read the reduced coefficients of an `Fq2` element stored as `c0 || c1`.
+#[cfg(any(
+    sp1_syscall = "BN254_FP2_ADD",
+    sp1_syscall = "BN254_FP2_SUB",
+    sp1_syscall = "BN254_FP2_MUL",
+))]
+unsafe fn read_fp2(p: *const [u32; 16]) -> [[u32; 8]; 2] {
+    let p = unsafe { &*p };
+    let reduce = |c: &[u32]| Q.reduce(c.try_into().unwrap());
+    [reduce(&p[..8]), reduce(&p[8..])]
+}
+
+/// Write an `Fq2` element as `c0 || c1`.
+#[cfg(any(
+    sp1_syscall = "BN254_FP2_ADD",
+    sp1_syscall = "BN254_FP2_SUB",
+    sp1_syscall = "BN254_FP2_MUL",
+))]
+unsafe fn write_fp2(p: *mut [u32; 16], c0: &[u32; 8], c1: &[u32; 8]) {
+    let p = unsafe { &mut *p };
+    p[..8].copy_from_slice(c0);
+    p[8..].copy_from_slice(c1);
+}
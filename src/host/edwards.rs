@@ -0,0 +1,79 @@
+//! Host implementation of the Ed25519 syscalls over affine points.
+//!
+//! A point is stored as `x || y`, 8 little-endian `u32` limbs each, on the curve
+//! `-x^2 + y^2 = 1 + d x^2 y^2`.
+
+use crate::arith;
+use crate::ed25519::{D, FIELD as F};
+
+/// `sqrt(-1) mod p`.
+const SQRT_M1: [u32; 8] = [
+    0x4a0ea0b0, 0xc4ee1b27, 0xad2fe478, 0x2f431806, 0x3dfbd7a7, 0x2b4d0099, 0x4fc1df0b, 0x2b832480,
+];
+
+/// `*p = *p + *q`.
+pub(super) unsafe fn add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    let (p, q) = unsafe { (&mut *p, &*q) };
+    let (x1, y1) = split(p);
+    let (x2, y2) = split(q);
+    let t = F.mul(&D, &F.mul(&F.mul(&x1, &x2), &F.mul(&y1, &y2)));
+    let x3 = F.mul(
+        &F.add(&F.mul(&x1, &y2), &F.mul(&y1, &x2)),
+        &F.inv(&F.add(&arith::one(), &t)),
+    );
+    let y3 = F.mul(
+        &F.add(&F.mul(&y1, &y2), &F.mul(&x1, &x2)),
+        &F.inv(&F.sub(&arith::one(), &t)),
+    );
+    p[..8].copy_from_slice(&x3);
+    p[8..].copy_from_slice(&y3);
+}
+
+/// Recover `x` from the encoding in `point[32..64]` and write it to `point[0..32]`, as
+/// little-endian bytes.
+pub(super) unsafe fn decompress(point: *mut [u8; 64]) {
+    let point = unsafe { &mut *point };
+    let mut y: [u32; 8] = core::array::from_fn(|i| {
+        u32::from_le_bytes(point[32 + 4 * i..36 + 4 * i].try_into().unwrap())
+    });
+    let sign = y[7] >> 31;
+    y[7] &= 0x7fff_ffff;
+    assert!(
+        arith::cmp(&y, &F.m).is_lt(),
+        "y is not a canonical field element"
+    );
+
+    let yy = F.mul(&y, &y);
+    let xx = F.mul(
+        &F.sub(&yy, &arith::one()),
+        &F.inv(&F.add(&F.mul(&D, &yy), &arith::one())),
+    );
+    // Square root for p = 5 mod 8: a candidate (xx)^((p + 3) / 8), fixed up by sqrt(-1).
+    let mut e = arith::add(&F.m, &[3, 0, 0, 0, 0, 0, 0, 0]).0;
+    for i in 0..8 {
+        e[i] = e[i] >> 3 | e.get(i + 1).map_or(0, |next| next << 29);
+    }
+    let mut x = F.pow(&xx, &e);
+    if F.mul(&x, &x) != xx {
+        x = F.mul(&x, &SQRT_M1);
+    }
+    assert!(
+        F.mul(&x, &x) == xx,
+        "y is not the coordinate of a curve point"
+    );
+    assert!(
+        !(arith::is_zero(&x) && sign == 1),
+        "x = 0 has no negative encoding"
+    );
+    if x[0] & 1 != sign {
+        x = F.neg(&x);
+    }
+    for (chunk, limb) in point[..32].chunks_exact_mut(4).zip(x) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+}
+
+/// Split a point into its coordinates.
+fn split(p: &[u32; 16]) -> ([u32; 8], [u32; 8]) {
+    (p[..8].try_into().unwrap(), p[8..].try_into().unwrap())
+}
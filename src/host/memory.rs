@@ -0,0 +1,7 @@
+//! Host implementation of the memcpy syscalls.
+
+/// Copy `LEN` bytes from `src` to `dst`, the ranges may overlap.
+#[cfg(any(sp1_syscall = "MEMCPY_32", sp1_syscall = "MEMCPY_64"))]
+pub(super) unsafe fn memcpy<const LEN: usize>(src: *const u8, dst: *mut u8) {
+    unsafe { core::ptr::copy(src, dst, LEN) }
+}
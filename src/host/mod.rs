@@ -0,0 +1,106 @@
+//! Host emulation of the SP1 syscalls.
+//!
+//! When the crate is built for any target other than the SP1 zkVM, the [`syscall!`] macro
+//! forwards every `ecall` to [`syscall`], which dispatches on the syscall ID to a pure-Rust
+//! implementation with the same memory semantics as the prover.
+//!
+//! [`syscall!`]: crate::syscall!
+
+mod bn254;
+mod edwards;
+mod keccak;
+mod memory;
+mod sha256;
+mod uint256;
+mod weierstrass;
+
+use core::cell::Cell;
+
+use crate::SyscallCode;
+
+std::thread_local! {
+    /// The number of syscalls executed by the current thread.
+    static COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The number of syscalls the current thread has executed on the host so far, e.g. to check
+/// how many `ecall`s an operation costs on the zkVM.
+pub fn count() -> usize {
+    COUNT.with(Cell::get)
+}
+
+/// Execute the syscall `syscall_id` with the arguments `args` (`a0`..`a3`) on the host,
+/// returning the value of `a0` after the call.
+///
+/// # Safety
+///
+/// The arguments must satisfy the safety contract of the wrapper issuing `syscall_id`.
+///
+/// # Panics
+///
+/// Panics if `syscall_id` is not a syscall known to this crate.
+pub unsafe fn syscall<const N: usize>(syscall_id: u32, args: [usize; N]) -> usize {
+    const { assert!(N <= 4, "syscalls take at most four arguments") };
+    let mut regs = [0; 4];
+    regs[..N].copy_from_slice(&args);
+    let [arg0, arg1, _, _] = regs;
+    COUNT.with(|count| count.set(count.get() + 1));
+
+    let Some(code) = SyscallCode::from_u32(syscall_id) else {
+        panic!("unsupported syscall: {syscall_id:#010x}");
+    };
+
+    unsafe {
+        match code {
+            SyscallCode::Bls12381Add => weierstrass::BLS12381.add(arg0 as _, arg1 as _),
+            SyscallCode::Bls12381Double => weierstrass::BLS12381.double(arg0 as _),
+            SyscallCode::Bls12381Decompress => {
+                weierstrass::BLS12381.decompress(arg0 as _, arg1 != 0)
+            }
+            #[cfg(sp1_syscall = "BN254_ADD")]
+            SyscallCode::Bn254Add => weierstrass::BN254.add(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_DOUBLE")]
+            SyscallCode::Bn254Double => weierstrass::BN254.double(arg0 as _),
+            #[cfg(sp1_syscall = "BN254_FP_ADD")]
+            SyscallCode::Bn254FpAdd => bn254::fp_add(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_FP_SUB")]
+            SyscallCode::Bn254FpSub => bn254::fp_sub(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_FP_MUL")]
+            SyscallCode::Bn254FpMul => bn254::fp_mul(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_FP2_ADD")]
+            SyscallCode::Bn254Fp2Add => bn254::fp2_add(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_FP2_SUB")]
+            SyscallCode::Bn254Fp2Sub => bn254::fp2_sub(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_FP2_MUL")]
+            SyscallCode::Bn254Fp2Mul => bn254::fp2_mul(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_SCALAR_MUL")]
+            SyscallCode::Bn254ScalarMul => bn254::scalar_mul(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "BN254_SCALAR_MAC")]
+            SyscallCode::Bn254ScalarMac => bn254::scalar_mac(arg0 as _, arg1 as _),
+            SyscallCode::EdAdd => edwards::add(arg0 as _, arg1 as _),
+            SyscallCode::EdDecompress => edwards::decompress(arg0 as _),
+            SyscallCode::KeccakPermute => keccak::permute(arg0 as _),
+            #[cfg(sp1_syscall = "MEMCPY_32")]
+            SyscallCode::Memcpy32 => memory::memcpy::<32>(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "MEMCPY_64")]
+            SyscallCode::Memcpy64 => memory::memcpy::<64>(arg0 as _, arg1 as _),
+            SyscallCode::Secp256k1Add => weierstrass::SECP256K1.add(arg0 as _, arg1 as _),
+            SyscallCode::Secp256k1Double => weierstrass::SECP256K1.double(arg0 as _),
+            SyscallCode::Secp256k1Decompress => {
+                weierstrass::SECP256K1.decompress(arg0 as _, arg1 != 0)
+            }
+            #[cfg(sp1_syscall = "SECP256R1_ADD")]
+            SyscallCode::Secp256r1Add => weierstrass::SECP256R1.add(arg0 as _, arg1 as _),
+            #[cfg(sp1_syscall = "SECP256R1_DOUBLE")]
+            SyscallCode::Secp256r1Double => weierstrass::SECP256R1.double(arg0 as _),
+            #[cfg(sp1_syscall = "SECP256R1_DECOMPRESS")]
+            SyscallCode::Secp256r1Decompress => {
+                weierstrass::SECP256R1.decompress(arg0 as _, arg1 != 0)
+            }
+            SyscallCode::ShaExtend => sha256::extend(arg0 as _),
+            SyscallCode::ShaCompress => sha256::compress(arg0 as _, arg1 as _),
+            SyscallCode::Uint256Mulmod => uint256::mulmod(arg0 as _, arg1 as _),
+        }
+    }
+    arg0
+}
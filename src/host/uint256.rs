@@ -0,0 +1,19 @@
+//! Host implementation of the 256-bit modular multiplication syscall.
+
+use crate::arith;
+
+/// `*x = *x * y mod modulus`, where `y_and_modulus` holds `y || modulus` and a zero modulus
+/// stands for `2^256`.
+pub(super) unsafe fn mulmod(x: *mut [u32; 8], y_and_modulus: *const [u32; 16]) {
+    unsafe {
+        let (y, modulus) = (*y_and_modulus).split_at(8);
+        let (y, modulus): (&[u32; 8], &[u32; 8]) =
+            (y.try_into().unwrap(), modulus.try_into().unwrap());
+        let (lo, hi) = arith::mul_wide(&*x, y);
+        *x = if arith::is_zero(modulus) {
+            lo
+        } else {
+            arith::div_rem_wide(&lo, &hi, modulus).1
+        };
+    }
+}
@@ -0,0 +1,136 @@
+//! Host implementation of the short Weierstrass curve syscalls over affine points.
+//!
+//! A point of a curve over an `N`-limb field is stored as `x || y`, `2N` little-endian `u32`
+//! limbs. Like the prover, the operations do not handle the point at infinity, and addition
+//! does not handle `P == ±Q`; those inputs panic.
+
+use crate::arith::{self, Modulus};
+
+/// The curve `y^2 = x^3 + ax + b` over the prime field of `p`, with `p = 3 mod 4`.
+pub(super) struct Curve<const N: usize> {
+    pub(super) p: Modulus<N>,
+    pub(super) a: [u32; N],
+    pub(super) b: [u32; N],
+}
+
+/// bn254 G1, `y^2 = x^3 + 3`.
+#[cfg(any(sp1_syscall = "BN254_ADD", sp1_syscall = "BN254_DOUBLE"))]
+pub(super) const BN254: Curve<8> = Curve {
+    p: crate::bn254::fq::MODULUS,
+    a: [0; 8],
+    b: [3, 0, 0, 0, 0, 0, 0, 0],
+};
+
+/// secp256k1, `y^2 = x^3 + 7`.
+pub(super) const SECP256K1: Curve<8> = Curve {
+    p: crate::secp256k1::FIELD,
+    a: [0; 8],
+    b: [7, 0, 0, 0, 0, 0, 0, 0],
+};
+
+/// secp256r1, `y^2 = x^3 - 3x + b`.
+#[cfg(any(
+    sp1_syscall = "SECP256R1_ADD",
+    sp1_syscall = "SECP256R1_DOUBLE",
+    sp1_syscall = "SECP256R1_DECOMPRESS",
+))]
+pub(super) const SECP256R1: Curve<8> = Curve {
+    p: crate::secp256r1::FIELD,
+    a: crate::secp256r1::A,
+    b: crate::secp256r1::B,
+};
+
+/// BLS12-381 G1, `y^2 = x^3 + 4`.
+pub(super) const BLS12381: Curve<12> = Curve {
+    p: crate::bls12_381::FIELD,
+    a: [0; 12],
+    b: [4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+};
+
+impl<const N: usize> Curve<N> {
+    /// `*p = *p + *q`, both points stored as `2N` limbs.
+    pub(super) unsafe fn add(&self, p: *mut u32, q: *const u32) {
+        let f = &self.p;
+        unsafe {
+            let (x1, y1) = read(p);
+            let (x2, y2) = read(q);
+            assert!(x1 != x2, "point addition requires P != ±Q");
+            let lambda = f.mul(&f.sub(&y2, &y1), &f.inv(&f.sub(&x2, &x1)));
+            let x3 = f.sub(&f.sub(&f.mul(&lambda, &lambda), &x1), &x2);
+            let y3 = f.sub(&f.mul(&lambda, &f.sub(&x1, &x3)), &y1);
+            write(p, &x3, &y3);
+        }
+    }
+
+    /// `*p = 2 * *p`, stored as `2N` limbs.
+    pub(super) unsafe fn double(&self, p: *mut u32) {
+        let f = &self.p;
+        unsafe {
+            let (x, y) = read(p);
+            assert!(!arith::is_zero(&y), "point doubling requires y != 0");
+            let x2 = f.mul(&x, &x);
+            let numerator = f.add(&f.add(&f.add(&x2, &x2), &x2), &self.a);
+            let lambda = f.mul(&numerator, &f.inv(&f.add(&y, &y)));
+            let x3 = f.sub(&f.mul(&lambda, &lambda), &f.add(&x, &x));
+            let y3 = f.sub(&f.mul(&lambda, &f.sub(&x, &x3)), &y);
+            write(p, &x3, &y3);
+        }
+    }
+
+    /// Recover `y` from `x` for the point stored as `8N` little-endian bytes: `x` is read
+    /// from the upper half and `y`, with the parity given by `is_odd`, is written to the
+    /// lower half.
+    pub(super) unsafe fn decompress(&self, point: *mut u8, is_odd: bool) {
+        let f = &self.p;
+        unsafe {
+            let x = f.reduce(&read_bytes(point.add(4 * N)));
+            let rhs = f.add(&f.mul(&f.add(&f.mul(&x, &x), &self.a), &x), &self.b);
+            let mut y = self
+                .sqrt(&rhs)
+                .expect("x is not the coordinate of a curve point");
+            if (y[0] & 1 == 1) != is_odd {
+                y = f.neg(&y);
+            }
+            write_bytes(point, &y);
+        }
+    }
+
+    /// Square root in the base field, using `p = 3 mod 4`.
+    fn sqrt(&self, a: &[u32; N]) -> Option<[u32; N]> {
+        let f = &self.p;
+        // (p + 1) / 4
+        let mut e = arith::add(&f.m, &arith::one()).0;
+        for i in 0..N {
+            e[i] = e[i] >> 2 | e.get(i + 1).map_or(0, |next| next << 30);
+        }
+        let root = f.pow(a, &e);
+        (f.mul(&root, &root) == *a).then_some(root)
+    }
+}
+
+/// Read a point stored as `2N` limbs.
+unsafe fn read<const N: usize>(p: *const u32) -> ([u32; N], [u32; N]) {
+    unsafe { (*(p as *const [u32; N]), *(p.add(N) as *const [u32; N])) }
+}
+
+/// Write a point as `2N` limbs.
+unsafe fn write<const N: usize>(p: *mut u32, x: &[u32; N], y: &[u32; N]) {
+    unsafe {
+        *(p as *mut [u32; N]) = *x;
+        *(p.add(N) as *mut [u32; N]) = *y;
+    }
+}
+
+/// Read an `N`-limb integer stored as little-endian bytes.
+unsafe fn read_bytes<const N: usize>(p: *const u8) -> [u32; N] {
+    let bytes = unsafe { core::slice::from_raw_parts(p, 4 * N) };
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
+
+/// Write an `N`-limb integer as little-endian bytes.
+unsafe fn write_bytes<const N: usize>(p: *mut u8, a: &[u32; N]) {
+    let bytes = unsafe { core::slice::from_raw_parts_mut(p, 4 * N) };
+    for (chunk, limb) in bytes.chunks_exact_mut(4).zip(a) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+}
@@ -0,0 +1,83 @@
+//! Keccak-f\[1600\] permutation precompile and a Keccak-256 hasher built on it.
+
+use crate::SyscallCode;
+
+/// `KECCAK_PERMUTE` syscall ID.
+pub const KECCAK_PERMUTE: u32 = SyscallCode::KeccakPermute.as_u32();
+
+/// Rate of Keccak-256 in bytes.
+const RATE: usize = 136;
+
+/// Apply the Keccak-f\[1600\] permutation to `state` in place.
+///
+/// The lanes are indexed as `state[x + 5 * y]`.
+///
+/// The signature ensures write access to all 25 lanes of `state`, so this function is safe.
+#[inline(always)]
+pub fn keccak_permute(state: &mut [u64; 25]) {
+    let state = state as *mut [u64; 25];
+    unsafe { crate::syscall!(KECCAK_PERMUTE, state, 0; options(nostack)) }
+}
+
+/// Streaming Keccak-256 hasher driven by the [`KECCAK_PERMUTE`] syscall.
+///
+/// This is the original Keccak padding used by Ethereum, not the SHA-3 one.
+#[derive(Clone, Debug)]
+pub struct Keccak256 {
+    state: [u64; 25],
+    /// Number of bytes absorbed into the current block.
+    absorbed: usize,
+}
+
+impl Default for Keccak256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keccak256 {
+    /// Create a hasher for a new message.
+    pub const fn new() -> Self {
+        Self {
+            state: [0; 25],
+            absorbed: 0,
+        }
+    }
+
+    /// Absorb `data` into the hash.
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.xor_byte(self.absorbed, byte);
+            self.absorbed += 1;
+            if self.absorbed == RATE {
+                keccak_permute(&mut self.state);
+                self.absorbed = 0;
+            }
+        }
+    }
+
+    /// Pad the message and return its digest.
+    pub fn finalize(mut self) -> [u8; 32] {
+        self.xor_byte(self.absorbed, 0x01);
+        self.xor_byte(RATE - 1, 0x80);
+        keccak_permute(&mut self.state);
+
+        let mut digest = [0u8; 32];
+        for (chunk, lane) in digest.chunks_exact_mut(8).zip(self.state) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        digest
+    }
+
+    /// XOR `byte` into the state at byte offset `index`.
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (8 * (index % 8));
+    }
+}
+
+/// Compute the Keccak-256 digest of `data`.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    hasher.finalize()
+}
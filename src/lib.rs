@@ -1,17 +1,151 @@
-#[cfg(not(all(target_os = "zkvm", target_vendor = "succinct")))]
+// The `memory::builtins` loops must not be turned back into calls to the functions they define.
+#![cfg_attr(feature = "override-mem-builtins", no_builtins)]
+
+#[cfg(not(any(
+    all(target_os = "zkvm", target_vendor = "succinct"),
+    feature = "host-emulation"
+)))]
 compile_error!("This crate is only meant to be compiled for sp1 zkvm.");
 
+#[cfg(not(any(feature = "sp1-v1", feature = "sp1-v2", feature = "sp1-v3")))]
+compile_error!(
+    "Select the SP1 release of the prover with the `sp1-v1`, `sp1-v2` or `sp1-v3` feature."
+);
+
+#[cfg(any(
+    all(feature = "sp1-v1", feature = "sp1-v2"),
+    all(feature = "sp1-v1", feature = "sp1-v3"),
+    all(feature = "sp1-v2", feature = "sp1-v3"),
+))]
+compile_error!("The `sp1-v1`, `sp1-v2` and `sp1-v3` features are mutually exclusive.");
+
+pub mod bls12_381;
 pub mod bn254;
+pub mod ed25519;
+pub mod groth16;
+pub mod keccak;
 pub mod memory;
+pub mod secp256k1;
+#[cfg(all(
+    sp1_syscall = "SECP256R1_ADD",
+    sp1_syscall = "SECP256R1_DOUBLE",
+    sp1_syscall = "SECP256R1_DECOMPRESS",
+))]
+pub mod secp256r1;
+pub mod sha256;
+pub mod syscall;
+pub mod uint256;
+
+#[cfg(feature = "macros")]
+pub use sp1_intrinsics_macros::sp1_syscall;
+pub use syscall::SyscallCode;
+
+mod arith;
+mod checked;
+#[cfg(not(all(target_os = "zkvm", target_vendor = "succinct")))]
+#[doc(hidden)]
+pub mod host;
+mod weierstrass;
+
+/// The checks of the `checked` feature, for the wrappers generated by `#[sp1_syscall]`.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __checked {
+    pub use crate::checked::{disjoint, pointer};
+}
 
+/// Issue the `ecall` for a syscall.
+///
+/// The syscall ID is passed in `t0` and up to four arguments in `a0`..`a3`, in order.
+/// Arguments must be integers or raw pointers.
+///
+/// * `syscall!(ID, arg0, arg1)` issues the syscall and discards any result.
+/// * `syscall!(ID, arg0 => ret)` assigns the value of `a0` after the call to the place `ret`.
+/// * `syscall!(ID, arg0, arg1; options(nostack))` forwards the options to [`asm!`].
+///
+/// [`asm!`]: core::arch::asm
+#[cfg(all(target_os = "zkvm", target_vendor = "succinct"))]
 #[macro_export]
 macro_rules! syscall {
-    ($syscall_id:expr, $arg0:expr, $arg1:expr) => {
+    ($syscall_id:expr $(, $args:expr)* $(=> $ret:expr)? $(; options($($opt:ident),* $(,)?))?) => {
+        $crate::__syscall_ecall!([$syscall_id] [$($args),*] [$($ret)?] [$($($opt),*)?])
+    };
+}
+
+/// Issue the `ecall` for a syscall.
+///
+/// The syscall ID is passed in `t0` and up to four arguments in `a0`..`a3`, in order.
+/// Arguments must be integers or raw pointers.
+///
+/// * `syscall!(ID, arg0, arg1)` issues the syscall and discards any result.
+/// * `syscall!(ID, arg0 => ret)` assigns the value of `a0` after the call to the place `ret`.
+/// * `syscall!(ID, arg0, arg1; options(nostack))` forwards the options to [`asm!`].
+///
+/// Off the zkVM, the syscall is executed by the host emulation backend and the options are ignored.
+///
+/// [`asm!`]: core::arch::asm
+#[cfg(not(all(target_os = "zkvm", target_vendor = "succinct")))]
+#[macro_export]
+macro_rules! syscall {
+    ($syscall_id:expr $(, $args:expr)* $(=> $ret:expr)? $(; options($($opt:ident),* $(,)?))?) => {{
+        let _ret = $crate::host::syscall($syscall_id, [$($args as usize),*]);
+        $($ret = _ret as _;)?
+    }};
+}
+
+/// Issue a syscall declared with a host implementation by `#[sp1_syscall]`.
+///
+/// On the zkVM this is [`syscall!`] with the registers after the argument list.
+#[cfg(all(target_os = "zkvm", target_vendor = "succinct"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_with_host {
+    ($host:path, [$($args:expr),*], $syscall_id:expr $(, $regs:expr)*) => {
+        $crate::syscall!($syscall_id $(, $regs)*; options(nostack))
+    };
+}
+
+/// Issue a syscall declared with a host implementation by `#[sp1_syscall]`.
+///
+/// Off the zkVM this calls the host implementation with the argument list.
+#[cfg(not(all(target_os = "zkvm", target_vendor = "succinct")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_with_host {
+    ($host:path, [$($args:expr),*], $syscall_id:expr $(, $regs:expr)*) => {
+        $host($($args),*)
+    };
+}
+
+/// Assign the arguments of [`syscall!`] to registers and emit the `ecall`.
+#[cfg(all(target_os = "zkvm", target_vendor = "succinct"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __syscall_ecall {
+    (@asm [$syscall_id:expr] [$($operands:tt)*] [$($opt:ident),*]) => {
         ::core::arch::asm!(
             "ecall",
             in("t0") $syscall_id,
-            in("a0") $arg0,
-            in("a1") $arg1,
+            $($operands)*
+            options($($opt),*)
         )
     };
+    (@regs [$syscall_id:expr] [$($operands:tt)*] [$reg:literal $($regs:literal)*] [$arg:expr $(, $args:expr)*] [$($opt:ident),*]) => {
+        $crate::__syscall_ecall!(@regs [$syscall_id] [$($operands)* in($reg) $arg,] [$($regs)*] [$($args),*] [$($opt),*])
+    };
+    (@regs [$syscall_id:expr] [$($operands:tt)*] [$($regs:literal)*] [] [$($opt:ident),*]) => {
+        $crate::__syscall_ecall!(@asm [$syscall_id] [$($operands)*] [$($opt),*])
+    };
+    ([$syscall_id:expr] [] [] [$($opt:ident),*]) => {
+        $crate::__syscall_ecall!(@asm [$syscall_id] [] [$($opt),*])
+    };
+    ([$syscall_id:expr] [] [$ret:expr] [$($opt:ident),*]) => {
+        $crate::__syscall_ecall!(@asm [$syscall_id] [lateout("a0") $ret,] [$($opt),*])
+    };
+    ([$syscall_id:expr] [$arg0:expr $(, $args:expr)*] [] [$($opt:ident),*]) => {
+        $crate::__syscall_ecall!(@regs [$syscall_id] [in("a0") $arg0,] ["a1" "a2" "a3"] [$($args),*] [$($opt),*])
+    };
+    ([$syscall_id:expr] [$arg0:expr $(, $args:expr)*] [$ret:expr] [$($opt:ident),*]) => {
+        $crate::__syscall_ecall!(@regs [$syscall_id] [inlateout("a0") $arg0 => $ret,] ["a1" "a2" "a3"] [$($args),*] [$($opt),*])
+    };
 }
@@ -0,0 +1,131 @@
+//! `memcpy`, `memmove`, `memset` and `memcmp` built on the memcpy syscalls.
+//!
+//! When building for the zkVM these functions are exported under their C names, replacing the
+//! compiler-builtins implementations for the whole program, so every struct move and buffer
+//! copy can use [`memcpy64`](super::memcpy64) and [`memcpy32`](super::memcpy32). The guest must
+//! not link any other definition of these symbols.
+//!
+//! Off the zkVM the functions are not exported, because the host emulation of the memcpy
+//! syscalls itself relies on the platform `memmove`.
+
+/// Length from which copies and fills use the memcpy syscalls instead of plain word loops.
+pub const SYSCALL_THRESHOLD: usize = 64;
+
+/// Copy `n` bytes from `src` to `dest`, returning `dest`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `src` must be [valid] for reads of `n` bytes.
+///
+/// * `dest` must be [valid] for writes of `n` bytes.
+///
+/// * The two ranges must not overlap.
+///
+/// [valid]: core::ptr#safety
+#[cfg_attr(all(target_os = "zkvm", target_vendor = "succinct"), no_mangle)]
+pub unsafe extern "C" fn memcpy(dest: *mut u8, src: *const u8, n: usize) -> *mut u8 {
+    unsafe { memmove(dest, src, n) }
+}
+
+/// Copy `n` bytes from `src` to `dest`, returning `dest`. The ranges may overlap.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `src` must be [valid] for reads of `n` bytes.
+///
+/// * `dest` must be [valid] for writes of `n` bytes, and must remain valid even
+///   when `src` is read for `n` bytes.
+///
+/// [valid]: core::ptr#safety
+#[cfg_attr(all(target_os = "zkvm", target_vendor = "succinct"), no_mangle)]
+pub unsafe extern "C" fn memmove(dest: *mut u8, src: *const u8, n: usize) -> *mut u8 {
+    unsafe { super::copy_with(src, dest, n, n >= SYSCALL_THRESHOLD) };
+    dest
+}
+
+/// Set `n` bytes starting at `s` to the byte `c`, returning `s`.
+///
+/// Large fills write the first 64-byte block with words and replicate it with [`memcpy64`].
+///
+/// # Safety
+///
+/// Behavior is undefined if `s` is not [valid] for writes of `n` bytes.
+///
+/// [valid]: core::ptr#safety
+/// [`memcpy64`]: super::memcpy64
+#[cfg_attr(all(target_os = "zkvm", target_vendor = "succinct"), no_mangle)]
+pub unsafe extern "C" fn memset(s: *mut u8, c: i32, n: usize) -> *mut u8 {
+    let byte = c as u8;
+    let word = u32::from_ne_bytes([byte; 4]);
+    unsafe {
+        let mut i = 0;
+        let head = s.align_offset(4).min(n);
+        while i < head {
+            *s.add(i) = byte;
+            i += 1;
+        }
+        if n - i >= SYSCALL_THRESHOLD + 64 {
+            let block = s.add(i) as *mut u32;
+            for j in 0..16 {
+                *block.add(j) = word;
+            }
+            i += 64;
+            while n - i >= 64 {
+                super::memcpy64(block as *const u32, s.add(i) as *mut u32);
+                i += 64;
+            }
+        }
+        while n - i >= 4 {
+            *(s.add(i) as *mut u32) = word;
+            i += 4;
+        }
+        while i < n {
+            *s.add(i) = byte;
+            i += 1;
+        }
+    }
+    s
+}
+
+/// Compare `n` bytes at `s1` and `s2`.
+///
+/// Returns zero if they are equal, otherwise the difference between the first pair of
+/// differing bytes, interpreted as `u8`.
+///
+/// # Safety
+///
+/// Behavior is undefined if `s1` or `s2` is not [valid] for reads of `n` bytes.
+///
+/// [valid]: core::ptr#safety
+#[cfg_attr(all(target_os = "zkvm", target_vendor = "succinct"), no_mangle)]
+pub unsafe extern "C" fn memcmp(s1: *const u8, s2: *const u8, n: usize) -> i32 {
+    unsafe {
+        let mut i = 0;
+        // Skip equal words when both pointers can be word aligned at once.
+        if (s1 as usize ^ s2 as usize).is_multiple_of(4) {
+            let head = s1.align_offset(4).min(n);
+            while i < head {
+                let (a, b) = (*s1.add(i), *s2.add(i));
+                if a != b {
+                    return a as i32 - b as i32;
+                }
+                i += 1;
+            }
+            while n - i >= 4 && *(s1.add(i) as *const u32) == *(s2.add(i) as *const u32) {
+                i += 4;
+            }
+        }
+        while i < n {
+            let (a, b) = (*s1.add(i), *s2.add(i));
+            if a != b {
+                return a as i32 - b as i32;
+            }
+            i += 1;
+        }
+    }
+    0
+}
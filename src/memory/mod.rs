@@ -0,0 +1,231 @@
+//! Memory intrinsics for SP1 zkVM.
+
+#[cfg(all(
+    feature = "override-mem-builtins",
+    sp1_syscall = "MEMCPY_32",
+    sp1_syscall = "MEMCPY_64",
+))]
+pub mod builtins;
+
+use crate::{checked, SyscallCode};
+
+/// `MEMCPY_32` syscall ID.
+#[cfg(sp1_syscall = "MEMCPY_32")]
+pub const SYSCALL_ID_MEMCPY_32: u32 = SyscallCode::Memcpy32.as_u32();
+/// `MEMCPY_64` syscall ID.
+#[cfg(sp1_syscall = "MEMCPY_64")]
+pub const SYSCALL_ID_MEMCPY_64: u32 = SyscallCode::Memcpy64.as_u32();
+
+/// Create 32 bytes bitwise copy from `src` to `dst`.
+/// The source and destination may overlap.
+///
+/// Like `core::ptr::copy`, the safety requirements are the same.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `src` must be [valid] for reads of 32 bytes, and must remain valid even
+///   when `dst` is written for 32 bytes. (This means if the memory ranges overlap,
+///   the two pointers must not be subject to aliasing restrictions relative to each other.)
+///
+/// * `dst` must be [valid] for writes of 32 bytes, and must remain valid even
+///   when `src` is read for 32 bytes.
+///
+/// * Both `src` and `dst` must be properly aligned.
+///
+/// If type be copied is not [`Copy`], using both the values in
+/// the region beginning at `*src` and the region beginning at `*dst` can
+/// [violate memory safety][read-ownership].
+///
+/// [valid]: core::ptr#safety
+/// [`read`]: core::ptr::read
+/// [`Copy`]: core::marker::Copy
+/// [read-ownership]: core::ptr::read#ownership-of-the-returned-value
+#[cfg(sp1_syscall = "MEMCPY_32")]
+#[inline(always)]
+pub unsafe fn memcpy32<T>(src: *const T, dst: *mut T) {
+    checked::pointer("src", src);
+    checked::pointer("dst", dst);
+    unsafe {
+        cfg_if::cfg_if! {
+            if #[cfg(not(feature = "disable-memcpy-syscalls"))] {
+                crate::syscall!(SYSCALL_ID_MEMCPY_32, src, dst; options(nostack))
+            } else {
+                core::ptr::copy(src as *const u8, dst as *mut u8, 32);
+            }
+        }
+    }
+}
+
+/// Create 64 bytes bitwise copy from `src` to `dst`.
+/// The source and destination may overlap.
+///
+/// Like `core::ptr::copy`, the safety requirements are the same.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `src` must be [valid] for reads of 64 bytes, and must remain valid even
+///   when `dst` is written for 64 bytes. (This means if the memory ranges overlap,
+///   the two pointers must not be subject to aliasing restrictions relative to each other.)
+///
+/// * `dst` must be [valid] for writes of 64 bytes, and must remain valid even
+///   when `src` is read for 64 bytes.
+///
+/// * Both `src` and `dst` must be properly aligned.
+///
+/// If type be copied is not [`Copy`], using both the values in
+/// the region beginning at `*src` and the region beginning at `*dst` can
+/// [violate memory safety][read-ownership].
+///
+/// [valid]: core::ptr#safety
+/// [`read`]: core::ptr::read
+/// [`Copy`]: core::marker::Copy
+/// [read-ownership]: core::ptr::read#ownership-of-the-returned-value
+#[cfg(sp1_syscall = "MEMCPY_64")]
+#[inline(always)]
+pub unsafe fn memcpy64<T>(src: *const T, dst: *mut T) {
+    checked::pointer("src", src);
+    checked::pointer("dst", dst);
+    unsafe {
+        cfg_if::cfg_if! {
+            if #[cfg(not(feature = "disable-memcpy-syscalls"))] {
+                crate::syscall!(SYSCALL_ID_MEMCPY_64, src, dst; options(nostack))
+            } else {
+                core::ptr::copy(src as *const u8, dst as *mut u8, 64);
+            }
+        }
+    }
+}
+
+/// Copy `src` into `dst`, using the memcpy syscalls for the bulk of the data.
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+pub fn copy(src: &[u8], dst: &mut [u8]) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "source and destination lengths differ"
+    );
+    // SAFETY: `src` is valid for reads and `dst` is valid for writes of `src.len()` bytes,
+    // and the borrows guarantee that the two slices do not overlap.
+    unsafe { copy_bytes(src.as_ptr(), dst.as_mut_ptr(), src.len()) }
+}
+
+/// Copy `len` bytes from `src` to `dst`. The source and destination may overlap.
+///
+/// The copy is split into 64-byte `memcpy64` blocks, then a 32-byte `memcpy32` block,
+/// then word and byte copies for the tail. The syscalls are only used when `src` and `dst`
+/// have the same alignment modulo 4, and when the selected syscall table has them; the
+/// unaligned head is copied byte by byte first.
+/// Overlapping ranges are copied front to back or back to front as needed, so the result
+/// is the same as [`core::ptr::copy`].
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `src` must be [valid] for reads of `len` bytes.
+///
+/// * `dst` must be [valid] for writes of `len` bytes, and must remain valid even
+///   when `src` is read for `len` bytes.
+///
+/// [valid]: core::ptr#safety
+pub unsafe fn copy_bytes(src: *const u8, dst: *mut u8, len: usize) {
+    unsafe { copy_with(src, dst, len, true) }
+}
+
+/// [`copy_bytes`], with the memcpy syscalls only used when `syscalls` is set.
+///
+/// # Safety
+///
+/// Same as [`copy_bytes`].
+unsafe fn copy_with(src: *const u8, dst: *mut u8, len: usize, syscalls: bool) {
+    let (src_addr, dst_addr) = (src as usize, dst as usize);
+    if len == 0 || src_addr == dst_addr {
+        return;
+    }
+
+    // Bulk copies are only possible when both pointers can be word aligned at once.
+    let bulk = (src_addr ^ dst_addr).is_multiple_of(4);
+    let head = if bulk {
+        dst.align_offset(4).min(len)
+    } else {
+        len
+    };
+
+    unsafe {
+        if dst_addr < src_addr || dst_addr >= src_addr + len {
+            copy_forward(src, dst, len, head, syscalls);
+        } else {
+            copy_backward(src, dst, len, head, syscalls);
+        }
+    }
+}
+
+/// Copy front to back, for `dst` below `src` or ranges that do not overlap.
+///
+/// `src.add(head)` and `dst.add(head)` must both be word aligned, unless `head == len`.
+unsafe fn copy_forward(src: *const u8, dst: *mut u8, len: usize, head: usize, syscalls: bool) {
+    unsafe {
+        let mut i = 0;
+        while i < head {
+            *dst.add(i) = *src.add(i);
+            i += 1;
+        }
+        #[cfg(sp1_syscall = "MEMCPY_64")]
+        while syscalls && len - i >= 64 {
+            memcpy64(src.add(i) as *const u32, dst.add(i) as *mut u32);
+            i += 64;
+        }
+        #[cfg(sp1_syscall = "MEMCPY_32")]
+        if syscalls && len - i >= 32 {
+            memcpy32(src.add(i) as *const u32, dst.add(i) as *mut u32);
+            i += 32;
+        }
+        while len - i >= 4 {
+            *(dst.add(i) as *mut u32) = *(src.add(i) as *const u32);
+            i += 4;
+        }
+        while i < len {
+            *dst.add(i) = *src.add(i);
+            i += 1;
+        }
+    }
+}
+
+/// Copy back to front, for `dst` above `src` with overlapping ranges.
+///
+/// `src.add(head)` and `dst.add(head)` must both be word aligned, unless `head == len`.
+unsafe fn copy_backward(src: *const u8, dst: *mut u8, len: usize, head: usize, syscalls: bool) {
+    unsafe {
+        let mut end = len;
+        let tail = (len - head) % 4;
+        while end > len - tail {
+            end -= 1;
+            *dst.add(end) = *src.add(end);
+        }
+        #[cfg(sp1_syscall = "MEMCPY_64")]
+        while syscalls && end - head >= 64 {
+            end -= 64;
+            memcpy64(src.add(end) as *const u32, dst.add(end) as *mut u32);
+        }
+        #[cfg(sp1_syscall = "MEMCPY_32")]
+        if syscalls && end - head >= 32 {
+            end -= 32;
+            memcpy32(src.add(end) as *const u32, dst.add(end) as *mut u32);
+        }
+        while end - head >= 4 {
+            end -= 4;
+            *(dst.add(end) as *mut u32) = *(src.add(end) as *const u32);
+        }
+        while end > 0 {
+            end -= 1;
+            *dst.add(end) = *src.add(end);
+        }
+    }
+}
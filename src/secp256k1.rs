@@ -0,0 +1,203 @@
+//! secp256k1 curve operations.
+//!
+//! Points are affine `x || y`, each coordinate 8 little-endian `u32` limbs. The point at
+//! infinity has no representation.
+//!
+//! [`ecrecover`] builds Ethereum-style public key recovery on top of the syscalls.
+
+use core::cmp::Ordering;
+
+use crate::arith::{self, Modulus};
+use crate::weierstrass::{Curve, Point};
+use crate::{checked, SyscallCode};
+
+/// `SECP256K1_ADD` syscall ID.
+pub const SECP256K1_ADD: u32 = SyscallCode::Secp256k1Add.as_u32();
+
+/// `SECP256K1_DOUBLE` syscall ID.
+pub const SECP256K1_DOUBLE: u32 = SyscallCode::Secp256k1Double.as_u32();
+
+/// `SECP256K1_DECOMPRESS` syscall ID.
+pub const SECP256K1_DECOMPRESS: u32 = SyscallCode::Secp256k1Decompress.as_u32();
+
+/// Perform in-place point addition `p += q`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`, and must remain valid even
+///   when `q` is read for `[u32; 16]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be points on the curve with canonical coordinates, and `p != ±q`.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_secp256k1_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        checked::binary(p, q, Some(&FIELD));
+        crate::syscall!(SECP256K1_ADD, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place point doubling `p = 2 * p`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`.
+///
+/// * `p` must be properly aligned.
+///
+/// * `p` must be a point on the curve with canonical coordinates.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_secp256k1_double(p: *mut [u32; 16]) {
+    unsafe {
+        checked::unary(p, Some(&FIELD));
+        crate::syscall!(SECP256K1_DOUBLE, p, 0; options(nostack))
+    }
+}
+
+/// Recover the `y` coordinate of a point from its `x` coordinate.
+///
+/// `point[32..64]` holds `x` as little-endian bytes. The syscall writes the `y` whose parity
+/// is `is_odd` to `point[0..32]`, as little-endian bytes.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `point` must be [valid] for reads and writes of `[u8; 64]`.
+///
+/// * `point` must be aligned to 4 bytes.
+///
+/// * `x` must be the coordinate of a point on the curve.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_secp256k1_decompress(point: *mut [u8; 64], is_odd: bool) {
+    checked::pointer("point", point);
+    unsafe { crate::syscall!(SECP256K1_DECOMPRESS, point, is_odd as u32; options(nostack)) }
+}
+
+/// The secp256k1 base field modulus `p`.
+pub(crate) const FIELD: Modulus<8> = Modulus::new([
+    0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+]);
+
+/// The order `n` of the secp256k1 group.
+const ORDER: Modulus<8> = Modulus::new([
+    0xd0364141, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff,
+]);
+
+/// The standard generator `G`.
+const GENERATOR: [u32; 16] = [
+    0x16f81798, 0x59f2815b, 0x2dce28d9, 0x029bfcdb, 0xce870b07, 0x55a06295, 0xf9dcbbac, 0x79be667e,
+    0xfb10d4b8, 0x9c47d08f, 0xa6855419, 0xfd17b448, 0x0e1108a8, 0x5da4fbfc, 0x26a3c465, 0x483ada77,
+];
+
+/// The curve constant `b = 7`.
+const B: [u32; 8] = [7, 0, 0, 0, 0, 0, 0, 0];
+
+/// secp256k1 for [`Point`] arithmetic.
+struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    unsafe fn add_assign(p: &mut [u32; 16], q: &[u32; 16]) {
+        // SAFETY: references are valid, aligned and cannot overlap; the caller guarantees the
+        // curve conditions.
+        unsafe { syscall_secp256k1_add(p, q) }
+    }
+
+    unsafe fn double_assign(p: &mut [u32; 16]) {
+        // SAFETY: as above.
+        unsafe { syscall_secp256k1_double(p) }
+    }
+}
+
+/// Recover the Ethereum address of the key that produced an ECDSA signature.
+///
+/// `msg_hash` is the 32-byte message digest, `sig` is `r || s` as big-endian integers and
+/// `recid` is the recovery id in `0..4` (Ethereum's `v - 27`). The address is the last 20
+/// bytes of the Keccak-256 hash of the recovered public key `x || y`, big-endian.
+///
+/// Returns `None` if the signature is malformed or no public key can be recovered. High `s`
+/// values are accepted, as in the `ecrecover` precompile.
+///
+/// This is safe to call: every point handed to the syscalls is either the generator or has
+/// been checked to lie on the curve, and the point at infinity and `P == ±Q` never reach them.
+pub fn ecrecover(msg_hash: &[u8; 32], sig: &[u8; 64], recid: u8) -> Option<[u8; 20]> {
+    if recid > 3 {
+        return None;
+    }
+    let r: [u32; 8] = arith::from_be_bytes(&sig[..32]);
+    let s: [u32; 8] = arith::from_be_bytes(&sig[32..]);
+    for k in [&r, &s] {
+        if arith::is_zero(k) || arith::cmp(k, &ORDER.m) != Ordering::Less {
+            return None;
+        }
+    }
+
+    // The x coordinate of the nonce point is `r`, or `r + n` for recovery ids 2 and 3.
+    let mut x = r;
+    if recid & 2 != 0 {
+        let (sum, carry) = arith::add(&r, &ORDER.m);
+        if carry {
+            return None;
+        }
+        x = sum;
+    }
+    if arith::cmp(&x, &FIELD.m) != Ordering::Less {
+        return None;
+    }
+    // The decompress syscall cannot be proven for an `x` off the curve, so check first.
+    let rhs = FIELD.add(&FIELD.mul(&FIELD.mul(&x, &x), &x), &B);
+    if !FIELD.is_square(&rhs) {
+        return None;
+    }
+    let mut buf = [0u32; 16];
+    for (word, limb) in buf[8..].iter_mut().zip(x) {
+        *word = limb.to_le();
+    }
+    // SAFETY: `buf` is a local 64-byte buffer aligned to 4 bytes, and `x` is the coordinate
+    // of a curve point as checked above.
+    unsafe { syscall_secp256k1_decompress(buf.as_mut_ptr() as *mut [u8; 64], recid & 1 == 1) };
+    let mut nonce = [0u32; 16];
+    nonce[..8].copy_from_slice(&x);
+    for (limb, word) in nonce[8..].iter_mut().zip(&buf[..8]) {
+        *limb = u32::from_le(*word);
+    }
+
+    // Q = r^-1 * (s * R - z * G)
+    let z = ORDER.reduce(&arith::from_be_bytes(msg_hash));
+    let r_inv = ORDER.inv(&r);
+    let u1 = ORDER.neg(&ORDER.mul(&z, &r_inv));
+    let u2 = ORDER.mul(&s, &r_inv);
+    // SAFETY: `GENERATOR` is on the curve, and the decompress syscall returns a curve point.
+    let (g, nonce) = unsafe {
+        (
+            Point::<Secp256k1>::new_unchecked(GENERATOR),
+            Point::new_unchecked(nonce),
+        )
+    };
+    let key = Point::mul_add(&u1, &g, &u2, &nonce).to_affine()?;
+
+    let mut bytes = [0u8; 64];
+    let (x, y) = key.split_at(8);
+    arith::to_be_bytes::<8>(x.try_into().unwrap(), &mut bytes[..32]);
+    arith::to_be_bytes::<8>(y.try_into().unwrap(), &mut bytes[32..]);
+    let hash = crate::keccak::keccak256(&bytes);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    Some(address)
+}
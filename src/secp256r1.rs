@@ -0,0 +1,189 @@
+//! secp256r1 (P-256) curve operations.
+//!
+//! Points are affine `x || y`, each coordinate 8 little-endian `u32` limbs. The point at
+//! infinity has no representation.
+//!
+//! [`ecdsa_verify`] builds ECDSA signature verification on top of the syscalls.
+
+use core::cmp::Ordering;
+
+use crate::arith::{self, Modulus};
+use crate::weierstrass::{Curve, Point};
+use crate::{checked, SyscallCode};
+
+/// `SECP256R1_ADD` syscall ID.
+pub const SECP256R1_ADD: u32 = SyscallCode::Secp256r1Add.as_u32();
+
+/// `SECP256R1_DOUBLE` syscall ID.
+pub const SECP256R1_DOUBLE: u32 = SyscallCode::Secp256r1Double.as_u32();
+
+/// `SECP256R1_DECOMPRESS` syscall ID.
+pub const SECP256R1_DECOMPRESS: u32 = SyscallCode::Secp256r1Decompress.as_u32();
+
+/// Perform in-place point addition `p += q`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`, and must remain valid even
+///   when `q` is read for `[u32; 16]`.
+///
+/// * `q` must be [valid] for reads of `[u32; 16]`.
+///
+/// * Both `p` and `q` must be properly aligned and not overlap.
+///
+/// * Both `p` and `q` must be points on the curve with canonical coordinates, and `p != ±q`.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_secp256r1_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    unsafe {
+        checked::binary(p, q, Some(&FIELD));
+        crate::syscall!(SECP256R1_ADD, p, q; options(nostack))
+    }
+}
+
+/// Perform in-place point doubling `p = 2 * p`.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `p` must be [valid] for reads and writes of `[u32; 16]`.
+///
+/// * `p` must be properly aligned.
+///
+/// * `p` must be a point on the curve with canonical coordinates.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_secp256r1_double(p: *mut [u32; 16]) {
+    unsafe {
+        checked::unary(p, Some(&FIELD));
+        crate::syscall!(SECP256R1_DOUBLE, p, 0; options(nostack))
+    }
+}
+
+/// Recover the `y` coordinate of a point from its `x` coordinate.
+///
+/// `point[32..64]` holds `x` as little-endian bytes. The syscall writes the `y` whose parity
+/// is `is_odd` to `point[0..32]`, as little-endian bytes.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following conditions are violated:
+///
+/// * `point` must be [valid] for reads and writes of `[u8; 64]`.
+///
+/// * `point` must be aligned to 4 bytes.
+///
+/// * `x` must be the coordinate of a point on the curve.
+///   Otherwise the result cannot be proven.
+///
+/// [valid]: core::ptr#safety
+#[inline(always)]
+pub unsafe fn syscall_secp256r1_decompress(point: *mut [u8; 64], is_odd: bool) {
+    checked::pointer("point", point);
+    unsafe { crate::syscall!(SECP256R1_DECOMPRESS, point, is_odd as u32; options(nostack)) }
+}
+
+/// The secp256r1 base field modulus `p`.
+pub(crate) const FIELD: Modulus<8> = Modulus::new([
+    0xffffffff, 0xffffffff, 0xffffffff, 0x00000000, 0x00000000, 0x00000000, 0x00000001, 0xffffffff,
+]);
+
+/// The order `n` of the secp256r1 group.
+const ORDER: Modulus<8> = Modulus::new([
+    0xfc632551, 0xf3b9cac2, 0xa7179e84, 0xbce6faad, 0xffffffff, 0xffffffff, 0x00000000, 0xffffffff,
+]);
+
+/// The standard generator `G`.
+const GENERATOR: [u32; 16] = [
+    0xd898c296, 0xf4a13945, 0x2deb33a0, 0x77037d81, 0x63a440f2, 0xf8bce6e5, 0xe12c4247, 0x6b17d1f2,
+    0x37bf51f5, 0xcbb64068, 0x6b315ece, 0x2bce3357, 0x7c0f9e16, 0x8ee7eb4a, 0xfe1a7f9b, 0x4fe342e2,
+];
+
+/// The curve constant `a = -3`.
+pub(crate) const A: [u32; 8] = [
+    0xfffffffc, 0xffffffff, 0xffffffff, 0x00000000, 0x00000000, 0x00000000, 0x00000001, 0xffffffff,
+];
+
+/// The curve constant `b`.
+pub(crate) const B: [u32; 8] = [
+    0x27d2604b, 0x3bce3c3e, 0xcc53b0f6, 0x651d06b0, 0x769886bc, 0xb3ebbd55, 0xaa3a93e7, 0x5ac635d8,
+];
+
+/// secp256r1 for [`Point`] arithmetic.
+struct Secp256r1;
+
+impl Curve for Secp256r1 {
+    unsafe fn add_assign(p: &mut [u32; 16], q: &[u32; 16]) {
+        // SAFETY: references are valid, aligned and cannot overlap; the caller guarantees the
+        // curve conditions.
+        unsafe { syscall_secp256r1_add(p, q) }
+    }
+
+    unsafe fn double_assign(p: &mut [u32; 16]) {
+        // SAFETY: as above.
+        unsafe { syscall_secp256r1_double(p) }
+    }
+}
+
+/// Verify an ECDSA signature over secp256r1.
+///
+/// `pubkey` is the uncompressed public key `x || y` and `sig` is `r || s`, all big-endian
+/// integers; `msg_hash` is the 32-byte message digest. Both low and high `s` values are
+/// accepted.
+///
+/// Returns `false` if the public key is not a point on the curve, if `r` or `s` is not in
+/// `1..n`, or if the signature does not match.
+///
+/// This is safe to call: the public key is checked to lie on the curve before reaching the
+/// syscalls, and the point at infinity and `P == ±Q` never reach them.
+pub fn ecdsa_verify(pubkey: &[u8; 64], msg_hash: &[u8; 32], sig: &[u8; 64]) -> bool {
+    let r: [u32; 8] = arith::from_be_bytes(&sig[..32]);
+    let s: [u32; 8] = arith::from_be_bytes(&sig[32..]);
+    for k in [&r, &s] {
+        if arith::is_zero(k) || arith::cmp(k, &ORDER.m) != Ordering::Less {
+            return false;
+        }
+    }
+
+    let x: [u32; 8] = arith::from_be_bytes(&pubkey[..32]);
+    let y: [u32; 8] = arith::from_be_bytes(&pubkey[32..]);
+    for c in [&x, &y] {
+        if arith::cmp(c, &FIELD.m) != Ordering::Less {
+            return false;
+        }
+    }
+    // y^2 = x^3 + ax + b
+    let rhs = FIELD.add(&FIELD.mul(&FIELD.add(&FIELD.mul(&x, &x), &A), &x), &B);
+    if FIELD.mul(&y, &y) != rhs {
+        return false;
+    }
+    let mut key = [0u32; 16];
+    key[..8].copy_from_slice(&x);
+    key[8..].copy_from_slice(&y);
+
+    // R = z / s * G + r / s * Q
+    let z = ORDER.reduce(&arith::from_be_bytes(msg_hash));
+    let s_inv = ORDER.inv(&s);
+    let u1 = ORDER.mul(&z, &s_inv);
+    let u2 = ORDER.mul(&r, &s_inv);
+    // SAFETY: `GENERATOR` is on the curve, and the public key was checked above.
+    let (g, key) = unsafe {
+        (
+            Point::<Secp256r1>::new_unchecked(GENERATOR),
+            Point::new_unchecked(key),
+        )
+    };
+    let Some(nonce) = Point::mul_add(&u1, &g, &u2, &key).to_affine() else {
+        return false;
+    };
+    let mut x = [0u32; 8];
+    x.copy_from_slice(&nonce[..8]);
+    ORDER.reduce(&x) == r
+}
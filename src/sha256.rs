@@ -0,0 +1,108 @@
+//! SHA-256 precompiles and a streaming hasher built on them.
+
+use crate::SyscallCode;
+
+/// `SHA_EXTEND` syscall ID.
+pub const SHA_EXTEND: u32 = SyscallCode::ShaExtend.as_u32();
+
+/// `SHA_COMPRESS` syscall ID.
+pub const SHA_COMPRESS: u32 = SyscallCode::ShaCompress.as_u32();
+
+/// The SHA-256 initial hash value.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// `SHA_EXTEND` syscall computes the message schedule `w[16..64]` from the block `w[0..16]`
+/// in place.
+///
+/// The signature ensures write access to the 64 words of `w`, so this function is safe.
+#[inline(always)]
+pub fn syscall_sha256_extend(w: &mut [u32; 64]) {
+    let w = w as *mut [u32; 64];
+    unsafe { crate::syscall!(SHA_EXTEND, w, 0; options(nostack)) }
+}
+
+/// `SHA_COMPRESS` syscall runs the 64 compression rounds over the message schedule `w`
+/// and adds the result into `state`.
+///
+/// The signature ensures access to the 64 words of `w` and the 8 words of `state`, and the
+/// two mutable borrows cannot overlap, so this function is safe.
+#[inline(always)]
+pub fn syscall_sha256_compress(w: &mut [u32; 64], state: &mut [u32; 8]) {
+    let (w, state) = (w as *mut [u32; 64], state as *mut [u32; 8]);
+    unsafe { crate::syscall!(SHA_COMPRESS, w, state; options(nostack)) }
+}
+
+/// Streaming SHA-256 hasher driven by the [`SHA_EXTEND`] and [`SHA_COMPRESS`] syscalls.
+#[derive(Clone, Debug)]
+pub struct Sha256 {
+    state: [u32; 8],
+    block: [u8; 64],
+    /// Number of buffered bytes in `block`.
+    buffered: usize,
+    /// Total message length in bytes.
+    len: u64,
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256 {
+    /// Create a hasher for a new message.
+    pub const fn new() -> Self {
+        Self {
+            state: IV,
+            block: [0; 64],
+            buffered: 0,
+            len: 0,
+        }
+    }
+
+    /// Absorb `data` into the hash.
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len += data.len() as u64;
+        while !data.is_empty() {
+            let take = (64 - self.buffered).min(data.len());
+            self.block[self.buffered..self.buffered + take].copy_from_slice(&data[..take]);
+            self.buffered += take;
+            data = &data[take..];
+            if self.buffered == 64 {
+                self.compress();
+            }
+        }
+    }
+
+    /// Pad the message and return its digest.
+    pub fn finalize(mut self) -> [u8; 32] {
+        let bit_len = self.len * 8;
+        self.block[self.buffered] = 0x80;
+        self.block[self.buffered + 1..].fill(0);
+        if self.buffered >= 56 {
+            self.compress();
+            self.block.fill(0);
+        }
+        self.block[56..].copy_from_slice(&bit_len.to_be_bytes());
+        self.compress();
+
+        let mut digest = [0u8; 32];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    /// Compress the full buffered block into the state.
+    fn compress(&mut self) {
+        let mut w = [0u32; 64];
+        for (word, chunk) in w.iter_mut().zip(self.block.chunks_exact(4)) {
+            *word = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        syscall_sha256_extend(&mut w);
+        syscall_sha256_compress(&mut w, &mut self.state);
+        self.buffered = 0;
+    }
+}
@@ -0,0 +1,223 @@
+//! The syscall codes understood by the SP1 zkVM.
+//!
+//! A code packs several fields into its little-endian bytes:
+//!
+//! * byte 0: the syscall number, unique among all syscalls;
+//! * byte 1: `1` if the syscall is proven by a precompile table, `0` otherwise;
+//! * byte 2: the number of extra cycles the syscall takes in the CPU;
+//! * byte 3: unused, always `0`.
+//!
+//! Only the syscalls listed in the pinned table under `syscalls/` of the SP1 release selected by
+//! the `sp1-v1`, `sp1-v2` or `sp1-v3` feature are compiled, and so are the bn254, memory and
+//! secp256r1 wrappers. The codes below are checked against that table at compile time.
+
+/// Define [`SyscallCode`] and the decoding of its raw values from a single list of codes.
+///
+/// Each code is only defined if the pinned table of the selected release lists it, see
+/// `build.rs`, and must then agree with that table.
+macro_rules! syscall_codes {
+    ($($(#[doc = $doc:literal])* $variant:ident = $code:literal => $name:literal,)*) => {
+        /// A syscall code, as passed in `t0` when issuing the `ecall`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(u32)]
+        #[non_exhaustive]
+        pub enum SyscallCode {
+            $($(#[doc = $doc])* #[cfg(sp1_syscall = $name)] $variant = $code,)*
+        }
+
+        impl SyscallCode {
+            /// Every syscall code known to this crate for the selected SP1 release.
+            pub const ALL: &'static [Self] = &[$(#[cfg(sp1_syscall = $name)] Self::$variant,)*];
+
+            /// Decode a raw syscall code, returning `None` if it is not known to this crate.
+            pub const fn from_u32(code: u32) -> Option<Self> {
+                match code {
+                    $(#[cfg(sp1_syscall = $name)] $code => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// The name of the code in SP1, as used by the pinned syscall tables.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(#[cfg(sp1_syscall = $name)] Self::$variant => $name,)*
+                }
+            }
+        }
+
+        // The codes agree with the pinned table of the selected release.
+        const _: () = {
+            $(
+                #[cfg(sp1_syscall = $name)]
+                assert!(
+                    table_code(SP1_TABLE, $name) == $code,
+                    concat!($name, " differs from the SP1 table of the selected release"),
+                );
+            )*
+        };
+    };
+}
+
+syscall_codes! {
+    /// Keccak-f\[1600\] permutation.
+    KeccakPermute = 0x00_01_01_09 => "KECCAK_PERMUTE",
+    /// SHA-256 message schedule extension.
+    ShaExtend = 0x00_30_01_05 => "SHA_EXTEND",
+    /// SHA-256 compression.
+    ShaCompress = 0x00_01_01_06 => "SHA_COMPRESS",
+    /// Ed25519 point addition.
+    EdAdd = 0x00_01_01_07 => "ED_ADD",
+    /// Ed25519 point decompression.
+    EdDecompress = 0x00_00_01_08 => "ED_DECOMPRESS",
+    /// secp256k1 point addition.
+    Secp256k1Add = 0x00_01_01_0A => "SECP256K1_ADD",
+    /// secp256k1 point doubling.
+    Secp256k1Double = 0x00_00_01_0B => "SECP256K1_DOUBLE",
+    /// secp256k1 point decompression.
+    Secp256k1Decompress = 0x00_00_01_0C => "SECP256K1_DECOMPRESS",
+    /// bn254 G1 point addition.
+    Bn254Add = 0x00_01_01_0E => "BN254_ADD",
+    /// bn254 G1 point doubling.
+    Bn254Double = 0x00_00_01_0F => "BN254_DOUBLE",
+    /// BLS12-381 point decompression.
+    Bls12381Decompress = 0x00_00_01_1C => "BLS12381_DECOMPRESS",
+    /// 256-bit modular multiplication, `UINT256_MUL` in SP1.
+    Uint256Mulmod = 0x00_01_01_1D => "UINT256_MUL",
+    /// BLS12-381 point addition.
+    Bls12381Add = 0x00_01_01_1E => "BLS12381_ADD",
+    /// BLS12-381 point doubling.
+    Bls12381Double = 0x00_00_01_1F => "BLS12381_DOUBLE",
+    /// bn254 base field addition.
+    Bn254FpAdd = 0x00_01_01_26 => "BN254_FP_ADD",
+    /// bn254 base field subtraction.
+    Bn254FpSub = 0x00_01_01_27 => "BN254_FP_SUB",
+    /// bn254 base field multiplication.
+    Bn254FpMul = 0x00_01_01_28 => "BN254_FP_MUL",
+    /// bn254 quadratic extension field addition.
+    Bn254Fp2Add = 0x00_01_01_29 => "BN254_FP2_ADD",
+    /// bn254 quadratic extension field subtraction.
+    Bn254Fp2Sub = 0x00_01_01_2A => "BN254_FP2_SUB",
+    /// bn254 quadratic extension field multiplication.
+    Bn254Fp2Mul = 0x00_01_01_2B => "BN254_FP2_MUL",
+    /// secp256r1 point addition.
+    Secp256r1Add = 0x00_01_01_2C => "SECP256R1_ADD",
+    /// secp256r1 point doubling.
+    Secp256r1Double = 0x00_00_01_2D => "SECP256R1_DOUBLE",
+    /// secp256r1 point decompression.
+    Secp256r1Decompress = 0x00_00_01_2E => "SECP256R1_DECOMPRESS",
+    /// bn254 scalar field multiplication.
+    Bn254ScalarMul = 0x00_01_01_80 => "BN254_SCALAR_MUL",
+    /// bn254 scalar field multiply-accumulate.
+    Bn254ScalarMac = 0x00_01_01_81 => "BN254_SCALAR_MAC",
+    /// Copy of 32 bytes.
+    Memcpy32 = 0x00_01_01_90 => "MEMCPY_32",
+    /// Copy of 64 bytes.
+    Memcpy64 = 0x00_01_01_91 => "MEMCPY_64",
+}
+
+impl SyscallCode {
+    /// The raw code, as passed in `t0`.
+    pub const fn as_u32(self) -> u32 {
+        self as u32
+    }
+
+    /// The syscall number in byte 0.
+    pub const fn syscall_id(self) -> u8 {
+        self.as_u32().to_le_bytes()[0]
+    }
+
+    /// Returns `true` if the syscall is proven by a precompile table rather than by the CPU
+    /// alone.
+    pub const fn has_precompile_table(self) -> bool {
+        self.as_u32().to_le_bytes()[1] == 1
+    }
+
+    /// The number of cycles the syscall takes in addition to the `ecall` itself.
+    pub const fn extra_cycles(self) -> u32 {
+        self.as_u32().to_le_bytes()[2] as u32
+    }
+}
+
+impl From<SyscallCode> for u32 {
+    fn from(code: SyscallCode) -> u32 {
+        code.as_u32()
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "sp1-v1")] {
+        /// The syscall table of the selected SP1 release.
+        const SP1_TABLE: &str = include_str!("../syscalls/sp1-v1.toml");
+    } else if #[cfg(feature = "sp1-v2")] {
+        /// The syscall table of the selected SP1 release.
+        const SP1_TABLE: &str = include_str!("../syscalls/sp1-v2.toml");
+    } else {
+        /// The syscall table of the selected SP1 release.
+        const SP1_TABLE: &str = include_str!("../syscalls/sp1-v3.toml");
+    }
+}
+
+/// Look up the code named `name` in a syscall table of `NAME = 0x..` lines.
+///
+/// This is just enough of a TOML parser for the pinned tables, and runs at compile time, where
+/// a name missing from the table is an error.
+const fn table_code(table: &str, name: &str) -> u32 {
+    let (table, name) = (table.as_bytes(), name.as_bytes());
+    let mut line = 0;
+    while line < table.len() {
+        let mut i = 0;
+        while i < name.len() && line + i < table.len() && table[line + i] == name[i] {
+            i += 1;
+        }
+        let mut pos = line + i;
+        if i == name.len() && pos + 5 <= table.len() {
+            let sep = [
+                table[pos],
+                table[pos + 1],
+                table[pos + 2],
+                table[pos + 3],
+                table[pos + 4],
+            ];
+            if matches!(sep, [b' ', b'=', b' ', b'0', b'x']) {
+                pos += 5;
+                let mut code = 0u32;
+                while pos < table.len() && table[pos] != b'\n' {
+                    let digit = match table[pos] {
+                        c @ b'0'..=b'9' => c - b'0',
+                        c @ b'a'..=b'f' => c - b'a' + 10,
+                        c @ b'A'..=b'F' => c - b'A' + 10,
+                        b'_' => {
+                            pos += 1;
+                            continue;
+                        }
+                        _ => panic!("invalid syscall code in the syscall table"),
+                    };
+                    code = (code << 4) | digit as u32;
+                    pos += 1;
+                }
+                return code;
+            }
+        }
+        while line < table.len() && table[line] != b'\n' {
+            line += 1;
+        }
+        line += 1;
+    }
+    panic!("syscall missing from the SP1 table of the selected release")
+}
+
+// No two codes share a syscall number, which the prover uses to route the `ecall` to its chip.
+const _: () = {
+    let mut i = 0;
+    while i < SyscallCode::ALL.len() {
+        let mut j = i + 1;
+        while j < SyscallCode::ALL.len() {
+            assert!(
+                SyscallCode::ALL[i].syscall_id() != SyscallCode::ALL[j].syscall_id(),
+                "syscall number used twice"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+};
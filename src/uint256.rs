@@ -0,0 +1,181 @@
+//! 256-bit modular multiplication precompile and a `U256` integer built on it.
+
+use core::cmp::Ordering;
+
+use crate::arith;
+use crate::SyscallCode;
+
+/// `UINT256_MULMOD` syscall ID, `UINT256_MUL` in SP1.
+pub const UINT256_MULMOD: u32 = SyscallCode::Uint256Mulmod.as_u32();
+
+/// Compute `x = x * y mod modulus` in place, where `y_and_modulus` holds `y || modulus` as
+/// little-endian limbs.
+///
+/// A modulus of zero stands for `2^256`, i.e. the product wraps. The inputs do not need to be
+/// reduced, and the result always is.
+///
+/// The signature ensures the access to both operands, so this function is safe.
+#[inline(always)]
+pub fn uint256_mulmod(x: &mut [u32; 8], y_and_modulus: &[u32; 16]) {
+    let x = x as *mut [u32; 8];
+    let y_and_modulus = y_and_modulus as *const [u32; 16];
+    unsafe { crate::syscall!(UINT256_MULMOD, x, y_and_modulus; options(nostack)) }
+}
+
+/// An unsigned 256-bit integer with modular arithmetic driven by the [`UINT256_MULMOD`]
+/// syscall.
+///
+/// The value is stored as little-endian `u32` limbs. Every modulus argument accepts zero as
+/// `2^256`, like the syscall itself.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C, align(4))]
+pub struct U256([u32; 8]);
+
+impl U256 {
+    /// The integer `0`.
+    pub const ZERO: Self = Self([0; 8]);
+
+    /// The integer `1`.
+    pub const ONE: Self = Self(arith::one());
+
+    /// The integer `2^256 - 1`.
+    pub const MAX: Self = Self([u32::MAX; 8]);
+
+    /// Create an integer from little-endian limbs.
+    pub const fn from_limbs(limbs: [u32; 8]) -> Self {
+        Self(limbs)
+    }
+
+    /// The little-endian limbs of the integer.
+    pub const fn to_limbs(&self) -> [u32; 8] {
+        self.0
+    }
+
+    /// Create an integer from its big-endian byte encoding.
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Self {
+        Self(arith::from_be_bytes(bytes))
+    }
+
+    /// The big-endian byte encoding of the integer.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        arith::to_be_bytes(&self.0, &mut bytes);
+        bytes
+    }
+
+    /// Create an integer from its little-endian byte encoding.
+    pub fn from_le_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0u32; 8];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(4)) {
+            *limb = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self(limbs)
+    }
+
+    /// The little-endian byte encoding of the integer.
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(4).zip(self.0) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns `true` if the integer is zero.
+    pub const fn is_zero(&self) -> bool {
+        arith::is_zero(&self.0)
+    }
+
+    /// The number of significant bits.
+    pub fn bits(&self) -> usize {
+        match self.0.iter().rposition(|&limb| limb != 0) {
+            Some(i) => 32 * (i + 1) - self.0[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Compute `self * rhs mod modulus`.
+    pub fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let mut x = self.0;
+        let mut y_and_modulus = [0u32; 16];
+        y_and_modulus[..8].copy_from_slice(&rhs.0);
+        y_and_modulus[8..].copy_from_slice(&modulus.0);
+        uint256_mulmod(&mut x, &y_and_modulus);
+        Self(x)
+    }
+
+    /// Compute `self^exp mod modulus`.
+    pub fn pow_mod(&self, exp: &Self, modulus: &Self) -> Self {
+        // `1 mod modulus`, which is zero for a modulus of one.
+        let mut acc = Self::ONE.mul_mod(&Self::ONE, modulus);
+        for i in (0..exp.bits()).rev() {
+            acc = acc.mul_mod(&acc, modulus);
+            if (exp.0[i / 32] >> (i % 32)) & 1 == 1 {
+                acc = acc.mul_mod(self, modulus);
+            }
+        }
+        acc
+    }
+
+    /// Compute `self^-1 mod modulus`, or `None` if `self` is not coprime to `modulus`.
+    ///
+    /// The modulus does not need to be prime. The inverse is found with the extended Euclidean
+    /// algorithm, with the divisions done in software.
+    pub fn inv_mod(&self, modulus: &Self) -> Option<Self> {
+        let a = if modulus.is_zero() {
+            self.0
+        } else {
+            arith::div_rem_wide(&self.0, &[0; 8], &modulus.0).1
+        };
+        if arith::is_zero(&a) {
+            // Only the trivial ring modulo one has `0` invertible.
+            return (*modulus == Self::ONE).then_some(Self::ZERO);
+        }
+
+        // The remainders `r` and the coefficients `t` with `t * self = r mod modulus`, starting
+        // from the first division `modulus = q * a + r`.
+        let hi = if modulus.is_zero() {
+            arith::one()
+        } else {
+            [0; 8]
+        };
+        let (q, r) = arith::div_rem_wide(&modulus.0, &hi, &a);
+        let (mut r0, mut r1) = (a, r);
+        let (mut t0, mut t1) = (Self::ONE, Self::ZERO.sub_mod(&Self(q), modulus));
+        while !arith::is_zero(&r1) {
+            let (q, r) = arith::div_rem_wide(&r0, &[0; 8], &r1);
+            (r0, r1) = (r1, r);
+            (t0, t1) = (t1, t0.sub_mod(&Self(q).mul_mod(&t1, modulus), modulus));
+        }
+        (r0 == arith::one()).then_some(t0)
+    }
+
+    /// Compute `self - rhs mod modulus` for `self, rhs < modulus`.
+    fn sub_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        match arith::sub(&self.0, &rhs.0) {
+            (d, true) => Self(arith::add(&d, &modulus.0).0),
+            (d, false) => Self(d),
+        }
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        let mut limbs = [0u32; 8];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        Self(limbs)
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        arith::cmp(&self.0, &other.0)
+    }
+}
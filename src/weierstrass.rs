@@ -0,0 +1,119 @@
+//! Affine short Weierstrass points on top of the curve add and double syscalls.
+//!
+//! The syscalls neither represent the point at infinity nor accept `P == ±Q`. [`Point`] handles
+//! both cases before reaching a syscall, so signature schemes can be written without `unsafe`.
+
+use core::marker::PhantomData;
+
+/// A curve over a 256-bit field whose points, `x || y` as 8 little-endian limbs each, are added
+/// and doubled by syscalls.
+pub(crate) trait Curve {
+    /// Compute `p += q`.
+    ///
+    /// # Safety
+    ///
+    /// Both `p` and `q` must be points on the curve with canonical coordinates, and `p != ±q`.
+    unsafe fn add_assign(p: &mut [u32; 16], q: &[u32; 16]);
+
+    /// Compute `p = 2 * p`.
+    ///
+    /// # Safety
+    ///
+    /// `p` must be a point on the curve with canonical coordinates and a nonzero `y`.
+    unsafe fn double_assign(p: &mut [u32; 16]);
+}
+
+/// A point of the curve `C`, `None` being the point at infinity.
+pub(crate) struct Point<C>(Option<[u32; 16]>, PhantomData<C>);
+
+impl<C> Clone for Point<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Point<C> {}
+
+impl<C: Curve> Point<C> {
+    /// The point at infinity.
+    pub(crate) const IDENTITY: Self = Self(None, PhantomData);
+
+    /// Wrap the affine point `xy`.
+    ///
+    /// # Safety
+    ///
+    /// `xy` must be a point on the curve with canonical coordinates.
+    pub(crate) const unsafe fn new_unchecked(xy: [u32; 16]) -> Self {
+        Self(Some(xy), PhantomData)
+    }
+
+    /// The affine coordinates, or `None` for the point at infinity.
+    pub(crate) const fn to_affine(self) -> Option<[u32; 16]> {
+        self.0
+    }
+
+    /// Compute `self + other`.
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let (mut p, q) = match (self.0, other.0) {
+            (None, _) => return *other,
+            (_, None) => return *self,
+            (Some(p), Some(q)) => (p, q),
+        };
+        if p[..8] == q[..8] {
+            // Same x: either the same point or its negation.
+            return if p[8..] == q[8..] {
+                self.double()
+            } else {
+                Self::IDENTITY
+            };
+        }
+        // SAFETY: both points are on the curve by the invariant of `Point`, and their `x`
+        // coordinates differ, so `p != ±q`.
+        unsafe { C::add_assign(&mut p, &q) };
+        Self(Some(p), PhantomData)
+    }
+
+    /// Compute `2 * self`.
+    pub(crate) fn double(&self) -> Self {
+        match self.0 {
+            Some(mut p) if p[8..].iter().any(|&limb| limb != 0) => {
+                // SAFETY: the point is on the curve by the invariant of `Point`, and `y != 0`.
+                unsafe { C::double_assign(&mut p) };
+                Self(Some(p), PhantomData)
+            }
+            // A point with `y == 0` has order two.
+            _ => Self::IDENTITY,
+        }
+    }
+
+    /// Compute `k * self` for a scalar given as little-endian limbs.
+    pub(crate) fn mul(&self, k: &[u32; 8]) -> Self {
+        Self::mul_add(k, self, &[0; 8], &Self::IDENTITY)
+    }
+
+    /// Compute `a * p + b * q` for scalars given as little-endian limbs.
+    ///
+    /// Both products share one pass of doublings over 4-bit windows, each window adding a
+    /// precomputed multiple of `p` and of `q`.
+    pub(crate) fn mul_add(a: &[u32; 8], p: &Self, b: &[u32; 8], q: &Self) -> Self {
+        let (p_table, q_table) = (Self::table(p), Self::table(q));
+        let digit = |k: &[u32; 8], i: usize| (k[i / 8] >> (4 * (i % 8))) as usize & 0xf;
+        let mut acc = Self::IDENTITY;
+        for i in (0..64).rev() {
+            for _ in 0..4 {
+                acc = acc.double();
+            }
+            acc = acc.add(&p_table[digit(a, i)]).add(&q_table[digit(b, i)]);
+        }
+        acc
+    }
+
+    /// The multiples `[0 * p, 1 * p, ..., 15 * p]`.
+    fn table(p: &Self) -> [Self; 16] {
+        let mut table = [Self::IDENTITY; 16];
+        for i in 1..16 {
+            table[i] = table[i - 1].add(p);
+        }
+        table
+    }
+}
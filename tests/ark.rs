@@ -0,0 +1,79 @@
+//! `bn254::ark::Fr` agrees with `ark_bn254::Fr`, and multiplies with one syscall per product.
+#![cfg(feature = "arkworks")]
+
+use ark_ff::{BigInteger, FftField, Field, PrimeField, UniformRand};
+use ark_std::test_rng;
+use sp1_intrinsics::bn254::ark::Fr;
+use sp1_intrinsics::host;
+
+type Reference = ark_bn254::Fr;
+
+fn convert(x: Reference) -> Fr {
+    Fr::from_bigint(x.into_bigint()).unwrap()
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Fr::MODULUS, Reference::MODULUS);
+    assert_eq!(Fr::GENERATOR, convert(Reference::GENERATOR));
+    assert_eq!(Fr::ONE, convert(Reference::ONE));
+    assert_eq!(
+        Fr::TWO_ADIC_ROOT_OF_UNITY,
+        convert(Reference::TWO_ADIC_ROOT_OF_UNITY)
+    );
+    assert!(Fr::from_bigint(Fr::MODULUS).is_none());
+}
+
+#[test]
+fn matches_reference() {
+    let mut rng = test_rng();
+    for _ in 0..16 {
+        let (a, b) = (Reference::rand(&mut rng), Reference::rand(&mut rng));
+        let (x, y) = (convert(a), convert(b));
+
+        assert_eq!(x.into_bigint(), a.into_bigint());
+        assert_eq!(x + y, convert(a + b));
+        assert_eq!(x - y, convert(a - b));
+        assert_eq!(-x, convert(-a));
+        assert_eq!(x.double(), convert(a.double()));
+        assert_eq!(x * y, convert(a * b));
+        assert_eq!(x.square(), convert(a.square()));
+        assert_eq!(x.inverse().unwrap(), convert(a.inverse().unwrap()));
+        assert_eq!(x.square().sqrt().map(|s| s.square()), Some(x.square()));
+        assert_eq!(x.to_string(), a.to_string());
+    }
+    assert!(Fr::from(0u64).inverse().is_none());
+    assert_eq!(Fr::from(6u64).into_bigint().to_bytes_le()[0], 6);
+}
+
+#[test]
+fn sum_of_products() {
+    let mut rng = test_rng();
+    let a: [Reference; 5] = core::array::from_fn(|_| Reference::rand(&mut rng));
+    let b: [Reference; 5] = core::array::from_fn(|_| Reference::rand(&mut rng));
+    let x = a.map(convert);
+    let y = b.map(convert);
+    assert_eq!(
+        Fr::sum_of_products(&x, &y),
+        convert(Reference::sum_of_products(&a, &b))
+    );
+}
+
+#[test]
+fn one_syscall_per_product() {
+    let mut rng = test_rng();
+    let (x, y) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+
+    let count = host::count();
+    let mut z = x * y;
+    assert_eq!(host::count() - count, 1);
+
+    let count = host::count();
+    z.square_in_place();
+    assert_eq!(host::count() - count, 1);
+
+    let (a, b) = ([x, y, z], [z, x, y]);
+    let count = host::count();
+    Fr::sum_of_products(&a, &b);
+    assert_eq!(host::count() - count, 3);
+}
@@ -0,0 +1,77 @@
+//! The BLS12-381 G1 syscall wrappers.
+
+use sp1_intrinsics::bls12_381::{
+    syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double,
+};
+
+/// Little-endian limbs of a big-endian hex integer.
+fn limbs(hex: &str) -> [u32; 12] {
+    core::array::from_fn(|i| u32::from_str_radix(&hex[88 - 8 * i..96 - 8 * i], 16).unwrap())
+}
+
+fn point(x: &str, y: &str) -> [u32; 24] {
+    let mut point = [0; 24];
+    point[..12].copy_from_slice(&limbs(x));
+    point[12..].copy_from_slice(&limbs(y));
+    point
+}
+
+const G_X: &str = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+const G_Y: &str = "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+
+#[test]
+fn add_and_double() {
+    let g = point(G_X, G_Y);
+    let mut p = g;
+    unsafe { syscall_bls12381_double(&mut p) };
+    assert_eq!(
+        p,
+        point(
+            "0572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e",
+            "166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28",
+        )
+    );
+
+    unsafe { syscall_bls12381_add(&mut p, &g) };
+    assert_eq!(
+        p,
+        point(
+            "09ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e5224",
+            "032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1",
+        )
+    );
+}
+
+/// The generator in the compressed encoding of the BLS12-381 specification (ZCash format).
+const G_COMPRESSED: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+
+#[test]
+fn decompress() {
+    #[repr(align(4))]
+    struct Bytes([u8; 96]);
+
+    let hex = |s: &str| -> Vec<u8> {
+        (0..s.len() / 2)
+            .map(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+            .collect()
+    };
+    let mut compressed = hex(G_COMPRESSED);
+    // The compression flag is set, the infinity and sign flags are not: `y` is the
+    // lexicographically smallest root.
+    assert_eq!(compressed[0] >> 5, 0b100);
+    compressed[0] &= 0x1f;
+
+    let mut roots = [true, false].map(|is_odd| {
+        let mut point = Bytes([0; 96]);
+        point.0[48..].copy_from_slice(&compressed);
+        point.0[48..].reverse();
+        unsafe { syscall_bls12381_decompress(&mut point.0, is_odd) };
+        let mut y = point.0[..48].to_vec();
+        y.reverse();
+        y
+    });
+    // Big-endian byte strings of the same length compare like the integers.
+    roots.sort();
+    assert_eq!(roots[0], hex(G_Y));
+    assert_ne!(roots[1], hex(G_Y));
+}
@@ -0,0 +1,88 @@
+//! The `memory::builtins` replacements agree with the platform functions.
+#![cfg(feature = "override-mem-builtins")]
+
+use sp1_intrinsics::memory::builtins;
+
+/// A word-aligned buffer with distinct bytes.
+#[repr(align(4))]
+struct Buffer([u8; 512]);
+
+impl Buffer {
+    fn new() -> Self {
+        Self(core::array::from_fn(|i| (i * 7 + 3) as u8))
+    }
+}
+
+const LENGTHS: [usize; 10] = [0, 1, 5, 32, 63, 64, 65, 129, 200, 400];
+
+#[test]
+fn memmove() {
+    for (src_offset, dst_offset) in [(0, 0), (0, 64), (64, 0), (3, 7), (1, 5), (6, 2)] {
+        for len in LENGTHS {
+            let mut actual = Buffer::new();
+            let mut expected = Buffer::new();
+            unsafe {
+                let base = actual.0.as_mut_ptr();
+                let ret = builtins::memmove(base.add(dst_offset), base.add(src_offset), len);
+                assert_eq!(ret, base.add(dst_offset));
+
+                let base = expected.0.as_mut_ptr();
+                core::ptr::copy(base.add(src_offset), base.add(dst_offset), len);
+            }
+            assert_eq!(
+                actual.0, expected.0,
+                "src {src_offset} dst {dst_offset} len {len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn memcpy() {
+    let src = Buffer::new();
+    for offset in [0, 1, 2] {
+        for len in LENGTHS {
+            let mut dst = Buffer([0; 512]);
+            unsafe { builtins::memcpy(dst.0.as_mut_ptr().add(offset), src.0.as_ptr(), len) };
+            assert_eq!(dst.0[offset..offset + len], src.0[..len]);
+        }
+    }
+}
+
+#[test]
+fn memset() {
+    for offset in [0, 1, 3] {
+        for len in LENGTHS {
+            let mut buffer = Buffer::new();
+            unsafe { builtins::memset(buffer.0.as_mut_ptr().add(offset), 0x1a5, len) };
+            let mut expected = Buffer::new();
+            expected.0[offset..offset + len].fill(0xa5);
+            assert_eq!(buffer.0, expected.0, "offset {offset} len {len}");
+        }
+    }
+}
+
+#[test]
+fn memcmp() {
+    let a = Buffer::new();
+    for offset in [0, 1] {
+        for len in LENGTHS {
+            for diff in [0, len / 2, len.saturating_sub(1)] {
+                let mut b = Buffer::new();
+                let equal = unsafe {
+                    builtins::memcmp(a.0.as_ptr().add(offset), b.0.as_ptr().add(offset), len)
+                };
+                assert_eq!(equal, 0);
+                if len == 0 {
+                    continue;
+                }
+                b.0[offset + diff] = b.0[offset + diff].wrapping_add(1);
+                let ordering = unsafe {
+                    builtins::memcmp(a.0.as_ptr().add(offset), b.0.as_ptr().add(offset), len)
+                };
+                let expected = a.0[offset + diff] as i32 - b.0[offset + diff] as i32;
+                assert_eq!(ordering, expected, "offset {offset} len {len} diff {diff}");
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! The contract checks of the syscall wrappers with the `checked` feature.
+#![cfg(feature = "checked")]
+
+use sp1_intrinsics::{bls12_381, bn254, memory};
+
+/// The bn254 scalar field modulus `r` as little-endian limbs.
+const R: [u32; 8] = [
+    0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// Wrappers declared with `#[sp1_syscall]`, which check their arguments too.
+#[cfg(feature = "macros")]
+mod declared {
+    use sp1_intrinsics::sp1_syscall;
+
+    /// Perform in-place 64-bit wrapping addition `x += y`.
+    #[sp1_syscall(id = 0x00_01_01_F7, args(x: mut u64, y: u64), host = host_add)]
+    pub(super) fn wrapping_add();
+
+    unsafe fn host_add(x: *mut u64, y: *const u64) {
+        unsafe { *x = (*x).wrapping_add(*y) }
+    }
+}
+
+/// A word-aligned buffer.
+#[repr(align(4))]
+struct Buffer([u8; 132]);
+
+#[test]
+fn valid_arguments_pass() {
+    let mut buffer = Buffer([1; 132]);
+    let p = buffer.0.as_mut_ptr();
+    // The memcpy syscalls allow overlapping ranges.
+    unsafe { memory::memcpy64(p, p.add(4)) };
+    unsafe { memory::memcpy32(p.add(64), p.add(100)) };
+
+    let mut ret = [1, 0, 0, 0, 0, 0, 0, 0];
+    let a = [2, 0, 0, 0, 0, 0, 0, 0];
+    // `a` and `b` are only read, so they may be the same.
+    unsafe { bn254::syscall_bn254_scalar_mac(&mut ret, &a, &a) };
+    assert_eq!(ret, [5, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "`dst` is not aligned to 4 bytes")]
+fn misaligned_pointer() {
+    let mut buffer = Buffer([0; 132]);
+    let p = buffer.0.as_mut_ptr();
+    unsafe { memory::memcpy32(p, p.add(66)) };
+}
+
+#[test]
+#[should_panic(expected = "`src` is null")]
+fn null_pointer() {
+    let mut dst = [0u32; 8];
+    unsafe { memory::memcpy32(core::ptr::null(), &mut dst) };
+}
+
+#[test]
+#[should_panic(expected = "`p` and `q` overlap")]
+fn overlapping_operands() {
+    let mut limbs = [0u32; 12];
+    let p = limbs.as_mut_ptr() as *mut [u32; 8];
+    unsafe { bn254::syscall_bn254_scalar_mul(p, p.cast::<u32>().add(4) as *const [u32; 8]) };
+}
+
+#[test]
+#[should_panic(expected = "`q` is not canonical")]
+fn non_canonical_scalar() {
+    let mut p = [1, 0, 0, 0, 0, 0, 0, 0];
+    unsafe { bn254::syscall_bn254_scalar_mul(&mut p, &R) };
+}
+
+#[test]
+#[should_panic(expected = "`p` is not canonical: limbs 8..16")]
+fn non_canonical_coordinate() {
+    // `y = 2^256 - 1` is above the base field modulus.
+    let mut p = [0u32; 16];
+    p[8..].fill(u32::MAX);
+    unsafe { bn254::syscall_bn254_double(&mut p) };
+}
+
+#[test]
+#[should_panic(expected = "`p` is not canonical: limbs 12..24")]
+fn non_canonical_bls12_381_coordinate() {
+    // `y = 2^384 - 1` is above the base field modulus.
+    let mut p = [0u32; 24];
+    p[12..].fill(u32::MAX);
+    unsafe { bls12_381::syscall_bls12381_double(&mut p) };
+}
+
+#[test]
+#[cfg(feature = "macros")]
+fn declared_syscall() {
+    assert_eq!(declared::WRAPPING_ADD, 0x00_01_01_F7);
+    let (mut x, y) = (u64::MAX, 2);
+    declared::wrapping_add(&mut x, &y);
+    assert_eq!(x, 1);
+}
+
+#[test]
+#[cfg(feature = "macros")]
+#[should_panic(expected = "`x` and `y` overlap")]
+fn declared_syscall_overlap() {
+    let mut limbs = [0u64; 2];
+    let x = limbs.as_mut_ptr();
+    unsafe { declared::syscall_wrapping_add(x, x.cast::<u32>().add(1).cast()) };
+}
+
+#[test]
+#[cfg(feature = "macros")]
+#[should_panic(expected = "`y` is not aligned to 4 bytes")]
+fn declared_syscall_misaligned() {
+    let mut buffer = Buffer([0; 132]);
+    let mut x = 0u64;
+    unsafe { declared::syscall_wrapping_add(&mut x, buffer.0.as_mut_ptr().add(2).cast()) };
+}
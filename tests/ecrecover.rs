@@ -0,0 +1,76 @@
+//! ECDSA public key recovery over the secp256k1 syscalls.
+
+use sp1_intrinsics::keccak::keccak256;
+use sp1_intrinsics::secp256k1::ecrecover;
+
+fn bytes<const N: usize>(hex: &str) -> [u8; N] {
+    core::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+}
+
+/// SHA-256 of `hello`, signed by the private key `1`.
+const HASH: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+const SIG: &str = "d47644539acec3da5e3ecf5fe8863c628a9c97e8b71e9ea9167a6f4f83c03c32\
+                   8194fc3a7c5395eded7b4fd24698fe45e00039e798e5f9e3789546c7f3133247";
+
+#[test]
+fn recovers_address_of_key_one() {
+    let address = ecrecover(&bytes(HASH), &bytes(SIG), 0).unwrap();
+    assert_eq!(
+        address,
+        bytes::<20>("7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+    );
+}
+
+#[test]
+fn recovers_odd_nonce() {
+    let hash = bytes("486ea46224d1bb4fb680f34f7c9ad96a8f24ec88be73ea8e5a6c65260e9cb8a7");
+    let sig = bytes(
+        "98ff240516ef428832ebb92d0e7f81868d651b8f236391c59e52c64d64f406dd\
+         bc513447e890ce52bda5e7eaa7c71fe72cb2b2ccd19842719b23d713cceab5e2",
+    );
+    let key: [u8; 64] = bytes(
+        "c03457aebb04b5343ee14b08f89a57bd842a7f6f1d39ec63a8cacc95cdeea779\
+         9bcd9ca350448e320e418c2f44b64087ce652a86004586e9a2d6c9661e74df60",
+    );
+    let address = ecrecover(&hash, &sig, 1).unwrap();
+    assert_eq!(address[..], keccak256(&key)[12..]);
+}
+
+#[test]
+fn wrong_recovery_id_recovers_another_key() {
+    let address = ecrecover(&bytes(HASH), &bytes(SIG), 1).unwrap();
+    assert_ne!(
+        address,
+        bytes::<20>("7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+    );
+}
+
+#[test]
+fn rejects_malformed_signatures() {
+    let hash = bytes(HASH);
+    let sig: [u8; 64] = bytes(SIG);
+    assert_eq!(ecrecover(&hash, &sig, 4), None);
+
+    let mut zero_r = sig;
+    zero_r[..32].fill(0);
+    assert_eq!(ecrecover(&hash, &zero_r, 0), None);
+
+    // s = n
+    let mut high_s = sig;
+    high_s[32..].copy_from_slice(&bytes::<32>(
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+    ));
+    assert_eq!(ecrecover(&hash, &high_s, 0), None);
+
+    // r + n overflows the base field.
+    assert_eq!(ecrecover(&hash, &sig, 2), None);
+}
+
+#[test]
+fn rejects_x_off_the_curve() {
+    // x = 5 is not the coordinate of a secp256k1 point.
+    let mut sig: [u8; 64] = bytes(SIG);
+    sig[..32].fill(0);
+    sig[31] = 5;
+    assert_eq!(ecrecover(&bytes(HASH), &sig, 0), None);
+}
@@ -0,0 +1,127 @@
+//! The Ed25519 syscall wrappers and signature verification.
+
+use sp1_intrinsics::ed25519::{syscall_ed_add, syscall_ed_decompress, verify};
+
+fn bytes<const N: usize>(hex: &str) -> [u8; N] {
+    core::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+}
+
+/// Little-endian limbs of a big-endian hex integer.
+fn limbs(hex: &str) -> [u32; 8] {
+    core::array::from_fn(|i| u32::from_str_radix(&hex[56 - 8 * i..64 - 8 * i], 16).unwrap())
+}
+
+fn point(x: &str, y: &str) -> [u32; 16] {
+    let mut point = [0; 16];
+    point[..8].copy_from_slice(&limbs(x));
+    point[8..].copy_from_slice(&limbs(y));
+    point
+}
+
+fn base() -> [u32; 16] {
+    point(
+        "216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a",
+        "6666666666666666666666666666666666666666666666666666666666666658",
+    )
+}
+
+#[test]
+fn add() {
+    let mut p = base();
+    unsafe { syscall_ed_add(&mut p, &base()) };
+    assert_eq!(
+        p,
+        point(
+            "36ab384c9f5a046c3d043b7d1833e7ac080d8e4515d7a45f83c5a14e2843ce0e",
+            "2260cdf3092329c21da25ee8c9a21f5697390f51643851560e5f46ae6af8a3c9",
+        )
+    );
+
+    // The identity (0, 1) is neutral.
+    let mut identity = [0; 16];
+    identity[8] = 1;
+    unsafe { syscall_ed_add(&mut identity, &base()) };
+    assert_eq!(identity, base());
+}
+
+#[test]
+fn decompress() {
+    #[repr(align(4))]
+    struct Bytes([u8; 64]);
+
+    let mut point = Bytes([0; 64]);
+    point.0[32..].copy_from_slice(&bytes::<32>(
+        "5866666666666666666666666666666666666666666666666666666666666666",
+    ));
+    unsafe { syscall_ed_decompress(&mut point.0) };
+    let x: Vec<u32> = point.0[..32]
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    assert_eq!(x, base()[..8]);
+}
+
+/// RFC 8032, 7.1, TEST 1.
+#[test]
+fn verifies_empty_message() {
+    let pubkey = bytes("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+    let sig = bytes(
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+         5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+    );
+    assert!(verify(&pubkey, b"", &sig));
+    assert!(!verify(&pubkey, b"\x00", &sig));
+}
+
+/// RFC 8032, 7.1, TEST 3.
+const PUBKEY: &str = "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025";
+const SIG: &str = "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac\
+                   18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a";
+
+#[test]
+fn verifies_short_message() {
+    assert!(verify(&bytes(PUBKEY), &[0xaf, 0x82], &bytes(SIG)));
+    assert!(!verify(&bytes(PUBKEY), &[0xaf, 0x83], &bytes(SIG)));
+}
+
+#[test]
+fn verifies_multi_block_message() {
+    let msg: Vec<u8> = (0..200).collect();
+    let sig = bytes(
+        "e5b9b8a0de60e42b4c1729d85c194e737ecf3330e4c26b5454b8fcd4db48e648\
+         8ffc7c024e3c19333a0263e59f963ed39a63c93e91b9ae20d60137b1f006ea04",
+    );
+    assert!(verify(&bytes(PUBKEY), &msg, &sig));
+}
+
+#[test]
+fn rejects_non_canonical_s() {
+    // S + L encodes the same scalar.
+    let mut sig: [u8; 64] = bytes(SIG);
+    let l = bytes::<32>("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+    let mut carry = 0u16;
+    for (s, l) in sig[32..].iter_mut().zip(l) {
+        let sum = *s as u16 + l as u16 + carry;
+        *s = sum as u8;
+        carry = sum >> 8;
+    }
+    assert!(!verify(&bytes(PUBKEY), &[0xaf, 0x82], &sig));
+}
+
+#[test]
+fn rejects_invalid_public_keys() {
+    let sig = bytes(SIG);
+    // The identity has small order.
+    let mut identity = [0u8; 32];
+    identity[0] = 1;
+    assert!(!verify(&identity, &[0xaf, 0x82], &sig));
+
+    // y = p is not canonical.
+    let non_canonical = bytes("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+    assert!(!verify(&non_canonical, &[0xaf, 0x82], &sig));
+
+    // y = 2 is not the coordinate of a curve point.
+    let mut off_curve = [0u8; 32];
+    off_curve[0] = 2;
+    assert!(!verify(&off_curve, &[0xaf, 0x82], &sig));
+}
@@ -0,0 +1,92 @@
+//! The `ff` trait implementations of `bn254::Fr`.
+#![cfg(feature = "ff")]
+
+use ff::{Field, FromUniformBytes, PrimeField};
+use rand_core::RngCore;
+use sp1_intrinsics::bn254::Fr;
+
+/// A deterministic xorshift generator, enough to exercise `Field::random`.
+struct XorShift(u64);
+
+impl RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Fr::TWO_INV.double(), Fr::ONE);
+    assert_eq!(Fr::ROOT_OF_UNITY * Fr::ROOT_OF_UNITY_INV, Fr::ONE);
+    assert_eq!(Fr::ROOT_OF_UNITY.pow_vartime([1u64 << Fr::S]), Fr::ONE);
+    assert_ne!(
+        Fr::ROOT_OF_UNITY.pow_vartime([1u64 << (Fr::S - 1)]),
+        Fr::ONE
+    );
+    assert_eq!(
+        Fr::MULTIPLICATIVE_GENERATOR.pow_vartime([1u64 << Fr::S]),
+        Fr::DELTA
+    );
+    assert_eq!(
+        Fr::from_str_vartime(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+        ),
+        Some(-Fr::ONE)
+    );
+}
+
+#[test]
+fn invert_and_sqrt() {
+    let mut rng = XorShift(0x0123_4567_89ab_cdef);
+    assert!(bool::from(Fr::ZERO.invert().is_none()));
+    for _ in 0..8 {
+        let x = Fr::random(&mut rng);
+        assert_eq!(x * x.invert().unwrap(), Fr::ONE);
+
+        let square = x.square();
+        let root = square.sqrt().unwrap();
+        assert!(root == x || root == -x);
+    }
+    assert!(bool::from(Fr::MULTIPLICATIVE_GENERATOR.sqrt().is_none()));
+}
+
+#[test]
+fn repr() {
+    let mut rng = XorShift(0xfeed_beef);
+    let x = Fr::random(&mut rng);
+    assert_eq!(Fr::from_repr(x.to_repr()).unwrap(), x);
+    assert!(bool::from(Fr::from_repr([0xff; 32]).is_none()));
+    assert!(bool::from(Fr::ONE.is_odd()));
+}
+
+#[test]
+fn from_uniform_bytes() {
+    let mut bytes = [0u8; 64];
+    bytes[32] = 1;
+    // 2^256 mod r
+    let two_256 = Fr::from(2).pow_vartime([256u64]);
+    assert_eq!(Fr::from_uniform_bytes(&bytes), two_256);
+
+    let bytes = [0xff; 64];
+    // 2^512 - 1
+    assert_eq!(Fr::from_uniform_bytes(&bytes), two_256.square() - Fr::ONE);
+}
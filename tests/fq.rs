@@ -0,0 +1,82 @@
+//! bn254 base field syscall wrappers and the safe `Fq`/`Fq2` types.
+
+#[cfg(not(feature = "sp1-v1"))]
+use sp1_intrinsics::bn254;
+use sp1_intrinsics::bn254::{Fq, Fq2};
+
+/// `q - 1` as little-endian limbs.
+const Q_MINUS_ONE: [u32; 8] = [
+    0xd87cfd46, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+fn fq2(c0: u64, c1: u64) -> Fq2 {
+    Fq2::new(Fq::from(c0), Fq::from(c1))
+}
+
+#[test]
+#[cfg(not(feature = "sp1-v1"))]
+fn fp_syscalls() {
+    let mut p = Q_MINUS_ONE;
+    unsafe { bn254::syscall_bn254_fp_mulmod(&mut p, &Q_MINUS_ONE) };
+    assert_eq!(p, [1, 0, 0, 0, 0, 0, 0, 0]);
+
+    unsafe { bn254::syscall_bn254_fp_addmod(&mut p, &Q_MINUS_ONE) };
+    assert_eq!(p, [0; 8]);
+
+    unsafe { bn254::syscall_bn254_fp_submod(&mut p, &[1, 0, 0, 0, 0, 0, 0, 0]) };
+    assert_eq!(p, Q_MINUS_ONE);
+}
+
+#[test]
+#[cfg(not(feature = "sp1-v1"))]
+fn fp2_syscalls() {
+    // u * u = -1
+    let mut u = [0; 16];
+    u[8] = 1;
+    let mut p = u;
+    unsafe { bn254::syscall_bn254_fp2_mulmod(&mut p, &u) };
+    let mut minus_one = [0; 16];
+    minus_one[..8].copy_from_slice(&Q_MINUS_ONE);
+    assert_eq!(p, minus_one);
+
+    unsafe { bn254::syscall_bn254_fp2_addmod(&mut p, &u) };
+    unsafe { bn254::syscall_bn254_fp2_submod(&mut p, &minus_one) };
+    assert_eq!(p, u);
+}
+
+#[test]
+fn fq_arithmetic() {
+    let a = Fq::from(0x1234_5678_9abc_def0);
+    let b = Fq::from(u64::MAX);
+
+    assert_eq!(Fq::from_limbs(Q_MINUS_ONE), Some(-Fq::ONE));
+    assert_eq!(Fq::from_bytes(&[0xff; 32]), None);
+    assert_eq!(a + b - b, a);
+    assert_eq!(a + -a, Fq::ZERO);
+    assert_eq!((a + b) * (a - b), a.square() - b.square());
+    assert_eq!(a.double(), Fq::from(2) * a);
+    assert_eq!(a * a.invert().unwrap(), Fq::ONE);
+    assert_eq!(Fq::ZERO.invert(), None);
+    assert_eq!(a.pow(&[3]), a * a * a);
+    assert_eq!([a, b].into_iter().product::<Fq>(), a * b);
+}
+
+#[test]
+fn fq2_arithmetic() {
+    let a = fq2(0x1234_5678_9abc_def0, 42);
+    let b = fq2(u64::MAX, 0xdead_beef);
+
+    assert_eq!(a.c0(), Fq::from(0x1234_5678_9abc_def0));
+    assert_eq!(a.c1(), Fq::from(42));
+    assert_eq!(a + b - b, a);
+    assert_eq!(a + -a, Fq2::ZERO);
+    assert_eq!((a + b) * (a - b), a.square() - b.square());
+    assert_eq!(fq2(1, 2) * fq2(3, 4), fq2(0, 10) - fq2(5, 0));
+    assert_eq!(a * a.invert().unwrap(), Fq2::ONE);
+    assert_eq!(Fq2::ZERO.invert(), None);
+
+    // The norm `a * conj(a)` lies in the base field.
+    assert_eq!((a * a.conjugate()).c1(), Fq::ZERO);
+    assert_eq!(Fq2::from(Fq::ONE), Fq2::ONE);
+    assert_eq!([a, b].into_iter().sum::<Fq2>(), a + b);
+}
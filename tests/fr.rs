@@ -0,0 +1,51 @@
+//! Arithmetic on the safe bn254 `Fr` wrapper.
+
+use sp1_intrinsics::bn254::Fr;
+
+/// `r` as little-endian bytes.
+const MODULUS_BYTES: [u8; 32] = [
+    0x01, 0x00, 0x00, 0xf0, 0x93, 0xf5, 0xe1, 0x43, 0x91, 0x70, 0xb9, 0x79, 0x48, 0xe8, 0x33, 0x28,
+    0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e, 0x64, 0x30,
+];
+
+#[test]
+fn bytes_range_check() {
+    assert_eq!(Fr::from_bytes(&MODULUS_BYTES), None);
+
+    let mut r_minus_one = MODULUS_BYTES;
+    r_minus_one[0] -= 1;
+    let x = Fr::from_bytes(&r_minus_one).unwrap();
+    assert_eq!(x, -Fr::ONE);
+    assert_eq!(x.to_bytes(), r_minus_one);
+
+    assert_eq!(Fr::from_bytes(&[0xff; 32]), None);
+    assert_eq!(Fr::from_bytes(&[0; 32]), Some(Fr::ZERO));
+}
+
+#[test]
+fn arithmetic() {
+    let a = Fr::from(0x1234_5678_9abc_def0);
+    let b = Fr::from(u64::MAX);
+
+    assert_eq!(a + b - b, a);
+    assert_eq!(a - a, Fr::ZERO);
+    assert_eq!(a + -a, Fr::ZERO);
+    assert_eq!(Fr::ZERO - Fr::ONE, -Fr::ONE);
+    assert_eq!(-Fr::ONE * -Fr::ONE, Fr::ONE);
+    assert_eq!((a + b) * (a - b), a * a - b * b);
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, a * b);
+    assert_eq!(Fr::from(6), Fr::from(2) * Fr::from(3));
+}
+
+#[test]
+fn mul_add() {
+    let a = Fr::from(0x1234_5678_9abc_def0);
+    let b = Fr::from(u64::MAX);
+    let c = -Fr::from(42);
+
+    assert_eq!(a.mul_add(&b, &c), a * b + c);
+    assert_eq!(a.mul_add(&a, &Fr::ZERO), a * a);
+}
@@ -0,0 +1,72 @@
+//! bn254 G1 syscall wrappers and [`G1Affine`].
+
+use sp1_intrinsics::bn254::{syscall_bn254_add, syscall_bn254_double, G1Affine};
+
+/// Little-endian limbs of a big-endian hex integer.
+fn limbs(hex: &str) -> [u32; 8] {
+    core::array::from_fn(|i| u32::from_str_radix(&hex[56 - 8 * i..64 - 8 * i], 16).unwrap())
+}
+
+fn point(x: &str, y: &str) -> [u32; 16] {
+    let mut point = [0; 16];
+    point[..8].copy_from_slice(&limbs(x));
+    point[8..].copy_from_slice(&limbs(y));
+    point
+}
+
+const G2_X: &str = "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3";
+const G2_Y: &str = "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4";
+const G3_X: &str = "0769bf9ac56bea3ff40232bcb1b6bd159315d84715b8e679f2d355961915abf0";
+const G3_Y: &str = "2ab799bee0489429554fdb7c8d086475319e63b40b9c5b57cdf1ff3dd9fe2261";
+
+#[test]
+fn add_and_double() {
+    let mut g = [0; 16];
+    g[0] = 1;
+    g[8] = 2;
+    let mut p = g;
+    unsafe { syscall_bn254_double(&mut p) };
+    assert_eq!(p, point(G2_X, G2_Y));
+
+    unsafe { syscall_bn254_add(&mut p, &g) };
+    assert_eq!(p, point(G3_X, G3_Y));
+}
+
+#[test]
+fn affine_arithmetic() {
+    let g = G1Affine::GENERATOR;
+    let g2 = G1Affine::new(limbs(G2_X), limbs(G2_Y)).unwrap();
+    let g3 = G1Affine::new(limbs(G3_X), limbs(G3_Y)).unwrap();
+
+    assert_eq!(g.double(), g2);
+    assert_eq!(g + g, g2);
+    assert_eq!(g2 + g, g3);
+    assert_eq!([g, g, g].into_iter().sum::<G1Affine>(), g3);
+}
+
+#[test]
+fn identity_handling() {
+    let g = G1Affine::GENERATOR;
+    let id = G1Affine::IDENTITY;
+
+    assert!(id.is_identity());
+    assert_eq!(id.to_xy(), None);
+    assert_eq!(id + g, g);
+    assert_eq!(g + id, g);
+    assert_eq!(id.double(), id);
+    assert_eq!(-id, id);
+    assert!((g + -g).is_identity());
+}
+
+#[test]
+fn rejects_points_off_the_curve() {
+    let mut one = [0; 8];
+    one[0] = 1;
+    let mut three = [0; 8];
+    three[0] = 3;
+    assert_eq!(G1Affine::new(one, three), None);
+
+    // x = q is not canonical.
+    let q = limbs("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+    assert_eq!(G1Affine::new(q, three), None);
+}
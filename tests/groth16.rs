@@ -0,0 +1,287 @@
+//! Groth16 verification with keys and proofs in the gnark and arkworks encodings.
+//!
+//! The fixed instance is synthetic: every point is a known multiple of a generator and `C` is
+//! solved from the verification equation, so it checks the verifier rather than a circuit.
+//! [`arkworks_prover`] also checks keys and proofs serialized by `ark-groth16` itself.
+
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_serialize::{CanonicalSerialize, Compress};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use ark_std::UniformRand;
+use sp1_intrinsics::bn254::Fr;
+use sp1_intrinsics::groth16::{verify, Proof, VerifyingKey};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn public_inputs() -> [Fr; 2] {
+    let x1 = hex("595a5a6ac6647816c9e9a0e011722632fd01d9d8a3140aa230ba2879e70bf629");
+    [
+        Fr::from(42),
+        Fr::from_bytes(&x1.try_into().unwrap()).unwrap(),
+    ]
+}
+
+const ARK_PROOF_COMPRESSED: &str = concat!(
+    "26f3632dda98a9907ccf05cd27c72f776811d2c4ee77b058dfde8a3bcc236915",
+    "59205137cf43b36745b7e5e75537908247ed18fe128554ab37e2782c79b2b819",
+    "9fc4184ffd3a7a6971ccd3f1cb06ff6f240a829b6f75078a133d635db78816af",
+    "f8218f97dd828cabf1d146fa26c3e0463eae56c4f7a782070b3ab2ff0a9b481f",
+);
+
+const ARK_VK_COMPRESSED: &str = concat!(
+    "19b15dd185442a0b1a373f489935f91314e878e2b5c62115fa345e10b2694881",
+    "cf32092c9ab1202f9ce857e2c2866381ce722531a2b1909df02acac221d10d22",
+    "3a2e7a4ee2738d95d3902d0fbbbd1230ca5c9b507ba23d21ed04027eeea20c21",
+    "fbb1744906d9eaa2ef913cbf36a7df4617a644467ac0855305e6e07467c74626",
+    "db3614f1f3390f3697bd2efe1f1ee067240a9df532ac76763160d6092c3272a7",
+    "24238fe95b2736af7dfb392fcb4fc2ea8f4f5bb7b228c4d10be92906c0f56d02",
+    "7c44cf4b642af1aea35f4be399f87e10ca39d888a7fc13954e1457ee7de4101f",
+    "0300000000000000ef493dfc801fb7a82cc12ae97f478224b8cdee518680a89d",
+    "1809b037557014aa13f867aeaaadbb7518526e466b1cd78bc55d60643eff8992",
+    "960d1e3d1009241f04b8493eeddc0dd4b62b8e8a0c292647af492bf6a151ea73",
+    "f704d6a3af80fa9e",
+);
+
+const GNARK_PROOF_COMPRESSED: &str = concat!(
+    "956923cc3b8adedf58b077eec4d21168772fc727cd05cf7c90a998da2d63f326",
+    "ef1688b75d633d138a07756f9b820a246fff06cbf1d3cc71697a3afd4f18c49f",
+    "19b8b2792c78e237ab548512fe18ed4782903755e7e5b74567b343cf37512059",
+    "9f489b0affb23a0b0782a7f7c456ae3e46e0c326fa46d1f1ab8c82dd978f21f8",
+);
+
+const GNARK_VK_COMPRESSED: &str = concat!(
+    "c14869b2105e34fa1521c6b5e278e81413f93599483f371a0b2a4485d15db119",
+    "de9fcfd3d8492ec43f02a6613352fe9987e779494fdf335c063e53ec7c8f80db",
+    "a10ca2ee7e0204ed213da27b509b5cca3012bdbb0f2d90d3958d73e24e7a2e3a",
+    "220dd121c2ca2af09d90b1a2312572ce816386c2e257e89c2f20b19a2c0932cf",
+    "e772322c09d660317676ac32f59d0a2467e01e1ffe2ebd97360f39f3f11436db",
+    "2646c76774e0e6055385c07a4644a61746dfa736bf3c91efa2ead9064974b1fb",
+    "da82dc80ae8b690a0e972c1edf592edb40512dd3ad79a259d06e67512196d8e5",
+    "9f10e47dee57144e9513fca788d839ca107ef899e34b5fa3aef12a644bcf447c",
+    "026df5c00629e90bd1c428b2b75b4f8feac24fcb2f39fb7daf36275be98f2324",
+    "00000003ea14705537b009189da8808651eecdb82482477fe92ac12ca8b71f80",
+    "fc3d49ef9f2409103d1e0d969289ff3e64605dc58bd71c6b466e521875bbadaa",
+    "ae67f813defa80afa3d604f773ea51a1f62b49af4726290c8a8e2bb6d40ddced",
+    "3e49b804",
+);
+
+const ARK_PROOF_UNCOMPRESSED: &str = concat!(
+    "26f3632dda98a9907ccf05cd27c72f776811d2c4ee77b058dfde8a3bcc236915",
+    "12a015dd95ae53593975772197ddb5b0e02429e00ed9a345036f9d5ed5c45c14",
+    "59205137cf43b36745b7e5e75537908247ed18fe128554ab37e2782c79b2b819",
+    "9fc4184ffd3a7a6971ccd3f1cb06ff6f240a829b6f75078a133d635db788162f",
+    "387e8ad64e615d8067167cec5e21b139d263abb081a5b4b4ef79270f8170061d",
+    "71aa59a9762d600215817315963ce05ea4e6db0e9d87dc45318aa9258209faa0",
+    "f8218f97dd828cabf1d146fa26c3e0463eae56c4f7a782070b3ab2ff0a9b481f",
+    "649db4e25a84aeae5b6ec96e2228a85c94b4dfebaaba702c60228934946b1404",
+);
+
+const ARK_VK_UNCOMPRESSED: &str = concat!(
+    "19b15dd185442a0b1a373f489935f91314e878e2b5c62115fa345e10b2694801",
+    "1efabec05166febe1f1d284b6173cdabb9ba3fe762b6846f34c1b2884ad6839e",
+    "cf32092c9ab1202f9ce857e2c2866381ce722531a2b1909df02acac221d10d22",
+    "3a2e7a4ee2738d95d3902d0fbbbd1230ca5c9b507ba23d21ed04027eeea20c21",
+    "0258f9af4b08504c35637886d4ab890472804d191f58020ef5a49b8c54ecb12d",
+    "1ea4c1eaddf338b99a023a7bb3fedfe815cbdc636ae4e070efc7969213f21d05",
+    "fbb1744906d9eaa2ef913cbf36a7df4617a644467ac0855305e6e07467c74626",
+    "db3614f1f3390f3697bd2efe1f1ee067240a9df532ac76763160d6092c327227",
+    "bc1f171b3d3aa59ba080072729951f95ccfe8d538a7fdc223283244d8113b62b",
+    "0c82c02583d1c16887dbd21dca386340e9ae6abe71dd541b7eda397a8bdbba9b",
+    "24238fe95b2736af7dfb392fcb4fc2ea8f4f5bb7b228c4d10be92906c0f56d02",
+    "7c44cf4b642af1aea35f4be399f87e10ca39d888a7fc13954e1457ee7de4101f",
+    "b5ebc08961edc523a12de4f07e40460c77820f0d78fc411e5a91bb874bfdea03",
+    "6f54b0a504b7d7919444ddd2c0680a8daa87428cfc0cb945d45faa61d97b4a13",
+    "0300000000000000ef493dfc801fb7a82cc12ae97f478224b8cdee518680a89d",
+    "1809b0375570142a38ec67ea197e17b67d71d49f778c617a8758920214df1d8e",
+    "b3a83e247feef7ad13f867aeaaadbb7518526e466b1cd78bc55d60643eff8992",
+    "960d1e3d1009241f8af7db31fc6de1f4e3cf1ff1dfc92f4158744585fb8c1f53",
+    "0af728938889481004b8493eeddc0dd4b62b8e8a0c292647af492bf6a151ea73",
+    "f704d6a3af80fa1ec626834c2c3401925353e1cbae18efb0ad003545a0e82dab",
+    "a7aa7fd1dba4489b",
+);
+
+const GNARK_PROOF_UNCOMPRESSED: &str = concat!(
+    "156923cc3b8adedf58b077eec4d21168772fc727cd05cf7c90a998da2d63f326",
+    "145cc4d55e9d6f0345a3d90ee02924e0b0b5dd97217775395953ae95dd15a012",
+    "2f1688b75d633d138a07756f9b820a246fff06cbf1d3cc71697a3afd4f18c49f",
+    "19b8b2792c78e237ab548512fe18ed4782903755e7e5b74567b343cf37512059",
+    "20fa098225a98a3145dc879d0edbe6a45ee03c961573811502602d76a959aa71",
+    "1d0670810f2779efb4b4a581b0ab63d239b1215eec7c1667805d614ed68a7e38",
+    "1f489b0affb23a0b0782a7f7c456ae3e46e0c326fa46d1f1ab8c82dd978f21f8",
+    "04146b94348922602c70baaaebdfb4945ca828226ec96e5baeae845ae2b49d64",
+);
+
+const GNARK_VK_UNCOMPRESSED: &str = concat!(
+    "014869b2105e34fa1521c6b5e278e81413f93599483f371a0b2a4485d15db119",
+    "1e83d64a88b2c1346f84b662e73fbab9abcd73614b281d1fbefe6651c0befa1e",
+    "1e9fcfd3d8492ec43f02a6613352fe9987e779494fdf335c063e53ec7c8f80db",
+    "2b7b6dd933bd2d8a0e3901f7230d9d4b6d051dcc9e536d308397bc9aea865d43",
+    "210ca2ee7e0204ed213da27b509b5cca3012bdbb0f2d90d3958d73e24e7a2e3a",
+    "220dd121c2ca2af09d90b1a2312572ce816386c2e257e89c2f20b19a2c0932cf",
+    "051df2139296c7ef70e0e46a63dccb15e8dffeb37b3a029ab938f3ddeac1a41e",
+    "2db1ec548c9ba4f50e02581f194d80720489abd4867863354c50084baff95802",
+    "2772322c09d660317676ac32f59d0a2467e01e1ffe2ebd97360f39f3f11436db",
+    "2646c76774e0e6055385c07a4644a61746dfa736bf3c91efa2ead9064974b1fb",
+    "1bbadb8b7a39da7e1b54dd71be6aaee9406338ca1dd2db8768c1d18325c0820c",
+    "2bb613814d24833222dc7f8a538dfecc951f9529270780a09ba53a3d1b171fbc",
+    "1a82dc80ae8b690a0e972c1edf592edb40512dd3ad79a259d06e67512196d8e5",
+    "1efde5cec20b93f57feed3b4faabf9e0e79e6b7ef2520a3253fccd9c00ebf3b0",
+    "1f10e47dee57144e9513fca788d839ca107ef899e34b5fa3aef12a644bcf447c",
+    "026df5c00629e90bd1c428b2b75b4f8feac24fcb2f39fb7daf36275be98f2324",
+    "134a7bd961aa5fd445b90cfc8c4287aa8d0a68c0d2dd449491d7b704a5b0546f",
+    "03eafd4b87bb915a1e41fc780d0f82770c46407ef0e42da123c5ed6189c0ebb5",
+    "000000032a14705537b009189da8808651eecdb82482477fe92ac12ca8b71f80",
+    "fc3d49ef2df7ee7f243ea8b38e1ddf14029258877a618c779fd4717db6177e19",
+    "ea67ec381f2409103d1e0d969289ff3e64605dc58bd71c6b466e521875bbadaa",
+    "ae67f813104889889328f70a531f8cfb85457458412fc9dff11fcfe3f4e16dfc",
+    "31dbf78a1efa80afa3d604f773ea51a1f62b49af4726290c8a8e2bb6d40ddced",
+    "3e49b8041b48a4dbd17faaa7ab2de8a0453500adb0ef18aecbe153539201342c",
+    "4c8326c6",
+);
+
+#[test]
+fn gnark() {
+    let vk = VerifyingKey::from_gnark_bytes(&hex(GNARK_VK_COMPRESSED)).unwrap();
+    let proof = Proof::from_gnark_bytes(&hex(GNARK_PROOF_COMPRESSED)).unwrap();
+    assert_eq!(
+        VerifyingKey::from_gnark_bytes(&hex(GNARK_VK_UNCOMPRESSED)),
+        Some(vk.clone())
+    );
+    assert_eq!(
+        Proof::from_gnark_bytes(&hex(GNARK_PROOF_UNCOMPRESSED)),
+        Some(proof)
+    );
+    assert_eq!(vk.ic.len(), 3);
+    assert!(verify(&vk, &proof, &public_inputs()));
+}
+
+#[test]
+fn arkworks() {
+    let vk = VerifyingKey::from_arkworks_bytes(&hex(ARK_VK_COMPRESSED)).unwrap();
+    let proof = Proof::from_arkworks_bytes(&hex(ARK_PROOF_COMPRESSED)).unwrap();
+    assert_eq!(
+        VerifyingKey::from_arkworks_bytes(&hex(ARK_VK_UNCOMPRESSED)),
+        Some(vk.clone())
+    );
+    assert_eq!(
+        Proof::from_arkworks_bytes(&hex(ARK_PROOF_UNCOMPRESSED)),
+        Some(proof)
+    );
+    assert_eq!(
+        VerifyingKey::from_gnark_bytes(&hex(GNARK_VK_COMPRESSED)),
+        Some(vk.clone())
+    );
+    assert!(verify(&vk, &proof, &public_inputs()));
+}
+
+#[test]
+fn rejects_wrong_inputs() {
+    let vk = VerifyingKey::from_gnark_bytes(&hex(GNARK_VK_COMPRESSED)).unwrap();
+    let proof = Proof::from_gnark_bytes(&hex(GNARK_PROOF_COMPRESSED)).unwrap();
+    let [x0, x1] = public_inputs();
+
+    assert!(!verify(&vk, &proof, &[x0 + Fr::ONE, x1]));
+    assert!(!verify(&vk, &proof, &[x0]));
+    assert!(!verify(&vk, &proof, &[x0, x1, Fr::ZERO]));
+
+    let swapped = Proof {
+        a: proof.c,
+        c: proof.a,
+        ..proof
+    };
+    assert!(!verify(&vk, &swapped, &[x0, x1]));
+}
+
+#[test]
+fn rejects_invalid_encodings() {
+    let proof = hex(ARK_PROOF_COMPRESSED);
+    assert_eq!(Proof::from_arkworks_bytes(&proof[..127]), None);
+
+    // Flip the sign of `A`, which then fails the check rather than the decoding.
+    let mut flipped = proof.clone();
+    flipped[31] ^= 0x80;
+    let flipped = Proof::from_arkworks_bytes(&flipped).unwrap();
+    assert_eq!(flipped.a, -Proof::from_arkworks_bytes(&proof).unwrap().a);
+
+    // Both flag bits set is not an arkworks encoding.
+    let mut invalid = proof;
+    invalid[31] |= 0xc0;
+    assert_eq!(Proof::from_arkworks_bytes(&invalid), None);
+
+    // A coordinate not below the modulus.
+    let mut invalid = hex(GNARK_PROOF_UNCOMPRESSED);
+    invalid[..32].fill(0x3f);
+    assert_eq!(Proof::from_gnark_bytes(&invalid), None);
+    assert_eq!(Proof::from_gnark_bytes(&[]), None);
+
+    let vk = hex(GNARK_VK_COMPRESSED);
+    assert_eq!(VerifyingKey::from_gnark_bytes(&vk[..vk.len() - 1]), None);
+}
+
+/// Knowledge of `w` with `w * a = b`, for the public inputs `a` and `b`.
+#[derive(Clone, Copy)]
+struct Product {
+    a: ark_bn254::Fr,
+    b: ark_bn254::Fr,
+    w: ark_bn254::Fr,
+}
+
+impl ConstraintSynthesizer<ark_bn254::Fr> for Product {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<ark_bn254::Fr>,
+    ) -> Result<(), SynthesisError> {
+        let a = cs.new_input_variable(|| Ok(self.a))?;
+        let b = cs.new_input_variable(|| Ok(self.b))?;
+        let w = cs.new_witness_variable(|| Ok(self.w))?;
+        cs.enforce_constraint(lc!() + w, lc!() + a, lc!() + b)
+    }
+}
+
+fn serialize(value: &impl CanonicalSerialize, compress: Compress) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value.serialize_with_mode(&mut bytes, compress).unwrap();
+    bytes
+}
+
+#[test]
+fn arkworks_prover() {
+    type Groth16 = ark_groth16::Groth16<ark_bn254::Bn254>;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    // The sign flags of the compressed G2 points, `beta`, `gamma`, `delta` and `B`, which
+    // depend on the order of the `Fq2` coefficients.
+    let mut signs = Vec::new();
+    for _ in 0..4 {
+        let (a, w) = (ark_bn254::Fr::rand(&mut rng), ark_bn254::Fr::rand(&mut rng));
+        let circuit = Product { a, b: a * w, w };
+        let (pk, ark_vk) = Groth16::circuit_specific_setup(circuit, &mut rng).unwrap();
+        let ark_proof = Groth16::prove(&pk, circuit, &mut rng).unwrap();
+        let inputs = [circuit.a, circuit.b]
+            .map(|x| Fr::from_bytes(&serialize(&x, Compress::Yes).try_into().unwrap()).unwrap());
+
+        let vk_bytes = serialize(&ark_vk, Compress::Yes);
+        let proof_bytes = serialize(&ark_proof, Compress::Yes);
+        let vk = VerifyingKey::from_arkworks_bytes(&vk_bytes).unwrap();
+        let proof = Proof::from_arkworks_bytes(&proof_bytes).unwrap();
+        assert!(verify(&vk, &proof, &inputs));
+        assert!(!verify(&vk, &proof, &[inputs[1], inputs[0]]));
+        for g2 in [32, 96, 160] {
+            signs.push(vk_bytes[g2 + 63] >> 7);
+        }
+        signs.push(proof_bytes[95] >> 7);
+
+        let vk_bytes = serialize(&ark_vk, Compress::No);
+        let proof_bytes = serialize(&ark_proof, Compress::No);
+        assert_eq!(VerifyingKey::from_arkworks_bytes(&vk_bytes), Some(vk));
+        assert_eq!(Proof::from_arkworks_bytes(&proof_bytes), Some(proof));
+    }
+    assert!(signs.contains(&0) && signs.contains(&1));
+}
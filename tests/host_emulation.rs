@@ -0,0 +1,79 @@
+//! The syscall wrappers executed through the host emulation backend.
+
+use sp1_intrinsics::{bn254, memory};
+
+const A: [u32; 8] = [
+    0x90abcdef, 0x12345678, 0x90abcdef, 0x12345678, 0x90abcdef, 0x12345678, 0x90abcdef, 0x12345678,
+];
+const B: [u32; 8] = [
+    0x87654321, 0x0fedcba9, 0x87654321, 0x0fedcba9, 0x87654321, 0x0fedcba9, 0x87654321, 0x0fedcba9,
+];
+const A_MUL_B: [u32; 8] = [
+    0x0026434b, 0x401063ea, 0xc3dd1413, 0x6ea73a00, 0x1020faff, 0xafb37e5b, 0x3d3804c5, 0x0dc5521a,
+];
+const R_MINUS_ONE: [u32; 8] = [
+    0xf0000000, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+#[test]
+fn bn254_scalar_mul() {
+    let mut p = A;
+    unsafe { bn254::syscall_bn254_scalar_mul(&mut p, &B) };
+    assert_eq!(p, A_MUL_B);
+
+    let mut p = R_MINUS_ONE;
+    unsafe { bn254::syscall_bn254_scalar_mul(&mut p, &R_MINUS_ONE) };
+    assert_eq!(p, [1, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn bn254_scalar_mac() {
+    // b + a * b
+    let mut ret = B;
+    unsafe { bn254::syscall_bn254_scalar_mac(&mut ret, &A, &B) };
+    assert_eq!(
+        ret,
+        [
+            0x878b866c, 0x4ffe2f93, 0x4b425734, 0x7e9505aa, 0x97863e20, 0xbfa14a04, 0xc49d47e6,
+            0x1db31dc3
+        ]
+    );
+
+    // 0 + a * b
+    let mut ret = [0u32; 8];
+    unsafe { bn254::syscall_bn254_scalar_mac(&mut ret, &A, &B) };
+    assert_eq!(ret, A_MUL_B);
+
+    // (r - 1) + (r - 1) * (r - 1) wraps around to zero.
+    let mut ret = R_MINUS_ONE;
+    unsafe { bn254::syscall_bn254_scalar_mac(&mut ret, &R_MINUS_ONE, &R_MINUS_ONE) };
+    assert_eq!(ret, [0; 8]);
+}
+
+#[test]
+fn bn254_scalar_mac_matches_mul() {
+    let mut product = A;
+    unsafe { bn254::syscall_bn254_scalar_mul(&mut product, &B) };
+
+    let mut ret = A_MUL_B;
+    unsafe { bn254::syscall_bn254_scalar_mac(&mut ret, &A, &B) };
+
+    let mut doubled = A_MUL_B;
+    let one = [1, 0, 0, 0, 0, 0, 0, 0];
+    unsafe { bn254::syscall_bn254_scalar_mac(&mut doubled, &product, &one) };
+    assert_eq!(ret, doubled);
+}
+
+#[test]
+fn memcpy() {
+    let src: [u32; 16] = core::array::from_fn(|i| i as u32 + 1);
+
+    let mut dst = [0u32; 16];
+    unsafe { memory::memcpy32(src.as_ptr(), dst.as_mut_ptr()) };
+    assert_eq!(dst[..8], src[..8]);
+    assert_eq!(dst[8..], [0; 8]);
+
+    let mut dst = [0u32; 16];
+    unsafe { memory::memcpy64(&src, &mut dst) };
+    assert_eq!(dst, src);
+}
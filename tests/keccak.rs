@@ -0,0 +1,74 @@
+//! The Keccak permutation syscall and the Keccak-256 hasher.
+
+use sp1_intrinsics::keccak::{keccak256, keccak_permute, Keccak256};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn permutation() {
+    let mut state = [0u64; 25];
+    keccak_permute(&mut state);
+    assert_eq!(state[0], 0xf1258f7940e1dde7);
+    assert_eq!(state[24], 0xeaf1ff7b5ceca249);
+}
+
+#[test]
+fn known_answers() {
+    let vectors: [(&[u8], &str); 3] = [
+        (
+            b"",
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            b"abc",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        ),
+        (
+            b"The quick brown fox jumps over the lazy dog",
+            "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+        ),
+    ];
+    for (message, expected) in vectors {
+        assert_eq!(keccak256(message).to_vec(), hex(expected));
+    }
+}
+
+/// SHA3-256 differs from Keccak-256 only in the padding, which checks multi-block absorption
+/// against a widely available reference.
+#[test]
+fn sha3_multi_block() {
+    let message: Vec<u8> = (0..2).flat_map(|_| 0..=255u8).collect();
+    let mut state = [0u64; 25];
+    let mut padded = message.clone();
+    padded.push(0x06);
+    padded.resize(padded.len().div_ceil(136) * 136, 0);
+    *padded.last_mut().unwrap() |= 0x80;
+    for block in padded.chunks(136) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        keccak_permute(&mut state);
+    }
+    let digest: Vec<u8> = state[..4]
+        .iter()
+        .flat_map(|lane| lane.to_le_bytes())
+        .collect();
+    assert_eq!(
+        digest,
+        hex("d4728ea5e9f3819f2b4760151a8f802dbe9f941fd6fb59b3715892436555772a")
+    );
+
+    let expected = keccak256(&message);
+    for chunk_len in [1, 135, 136, 137, 300] {
+        let mut hasher = Keccak256::new();
+        for chunk in message.chunks(chunk_len) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), expected, "chunk length {chunk_len}");
+    }
+}
@@ -0,0 +1,60 @@
+//! Arbitrary-length copies built on the memcpy syscalls.
+
+use sp1_intrinsics::memory;
+
+/// A word-aligned buffer with distinct bytes.
+#[repr(align(4))]
+struct Buffer([u8; 512]);
+
+impl Buffer {
+    fn new() -> Self {
+        Self(core::array::from_fn(|i| (i * 7 + 3) as u8))
+    }
+}
+
+#[test]
+fn copy_slices() {
+    let src = Buffer::new();
+    for (src_offset, dst_offset) in [(0, 0), (1, 1), (3, 3), (0, 1), (2, 1)] {
+        for len in [0, 1, 3, 4, 31, 32, 33, 63, 64, 65, 100, 200, 300] {
+            let mut dst = [0u8; 512];
+            memory::copy(
+                &src.0[src_offset..src_offset + len],
+                &mut dst[dst_offset..dst_offset + len],
+            );
+            assert_eq!(
+                dst[dst_offset..dst_offset + len],
+                src.0[src_offset..src_offset + len]
+            );
+            assert!(dst[..dst_offset].iter().all(|&b| b == 0));
+            assert!(dst[dst_offset + len..].iter().all(|&b| b == 0));
+        }
+    }
+}
+
+#[test]
+fn copy_overlapping() {
+    for (src_offset, dst_offset) in [(0, 4), (4, 0), (0, 64), (64, 0), (1, 37), (37, 1), (5, 6)] {
+        for len in [1, 7, 32, 64, 65, 130, 250, 400] {
+            let mut actual = Buffer::new();
+            let mut expected = Buffer::new();
+            unsafe {
+                let base = actual.0.as_mut_ptr();
+                memory::copy_bytes(base.add(src_offset), base.add(dst_offset), len);
+
+                let base = expected.0.as_mut_ptr();
+                core::ptr::copy(base.add(src_offset), base.add(dst_offset), len);
+            }
+            assert_eq!(
+                actual.0, expected.0,
+                "src {src_offset} dst {dst_offset} len {len}"
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "lengths differ")]
+fn copy_length_mismatch() {
+    memory::copy(&[0; 4], &mut [0; 5]);
+}
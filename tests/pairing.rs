@@ -0,0 +1,134 @@
+//! The bn254 pairing check on EIP-197 encoded inputs.
+//!
+//! The inputs are big-endian 32-byte words: `x, y` of each G1 point followed by
+//! `x.c1, x.c0, y.c1, y.c0` of the G2 point. The points are multiples of the generators,
+//! `e(a * P, b * Q) * e(-(a * b) * P, Q)` and similar.
+
+use sp1_intrinsics::bn254::{pairing_check, Fq, Fq2, G1Affine, G2Affine};
+
+/// Little-endian limbs of a big-endian hex integer.
+fn limbs(hex: &str) -> [u32; 8] {
+    core::array::from_fn(|i| u32::from_str_radix(&hex[56 - 8 * i..64 - 8 * i], 16).unwrap())
+}
+
+fn fq2(c1: &str, c0: &str) -> Fq2 {
+    Fq2::new(
+        Fq::from_limbs(limbs(c0)).unwrap(),
+        Fq::from_limbs(limbs(c1)).unwrap(),
+    )
+}
+
+/// Decode the EIP-197 words into pairs, panicking on invalid points.
+fn decode(words: &[&str]) -> Vec<(G1Affine, G2Affine)> {
+    assert_eq!(words.len() % 6, 0);
+    words
+        .chunks_exact(6)
+        .map(|w| {
+            let zero = |w: &[&str]| w.iter().all(|w| w.bytes().all(|b| b == b'0'));
+            let p = match zero(&w[..2]) {
+                true => G1Affine::IDENTITY,
+                false => G1Affine::new(limbs(w[0]), limbs(w[1])).unwrap(),
+            };
+            let q = match zero(&w[2..]) {
+                true => G2Affine::IDENTITY,
+                false => G2Affine::new(fq2(w[2], w[3]), fq2(w[4], w[5])).unwrap(),
+            };
+            (p, q)
+        })
+        .collect()
+}
+
+const MATCH: &[&str] = &[
+    "1107937ddc8584b7640f7d7915258d9c51fef5cfaeeb963826016ca1c8519518",
+    "28c57008351d171d012de5ae4d1985fa7271ec6471749e6c0b3c26f815fdb9d8",
+    "166fec85611f16cef4b696de813d071a3f10c000acb839e14ec52317bd2e2e9e",
+    "206800b59c9c720c2e2bed8a29da13f7f581f35c0fd6109086ba8025b74669f1",
+    "0f0da7e37531ea28610f8b471bf7778da9b0d8e446fded2cc4e877bdb989e66f",
+    "042ea058eebebb860a9e9fc7a074f0b962b5c0fa1e6ff02d276a8bda7d929a6c",
+    "12988d8317bb78c3b9be75430e90dfedda4a0d09e44cea23a70d9e3ee4090965",
+    "156bd8c73c5baa36888333549eafc7eac7b5f60e8caf52761f0457531fc7aa31",
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+];
+
+const MISMATCH: &[&str] = &[
+    "1107937ddc8584b7640f7d7915258d9c51fef5cfaeeb963826016ca1c8519518",
+    "28c57008351d171d012de5ae4d1985fa7271ec6471749e6c0b3c26f815fdb9d8",
+    "166fec85611f16cef4b696de813d071a3f10c000acb839e14ec52317bd2e2e9e",
+    "206800b59c9c720c2e2bed8a29da13f7f581f35c0fd6109086ba8025b74669f1",
+    "0f0da7e37531ea28610f8b471bf7778da9b0d8e446fded2cc4e877bdb989e66f",
+    "042ea058eebebb860a9e9fc7a074f0b962b5c0fa1e6ff02d276a8bda7d929a6c",
+    "132ec0d22db4d6f72a822feff0b0ca1811b383d72e88158a86dac643744b9aad",
+    "166b186b5fff388a6588fbd655470ec0d8ab31505c0573d3aa213940083abf6d",
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+];
+
+const THREE_POINT: &[&str] = &[
+    "1107937ddc8584b7640f7d7915258d9c51fef5cfaeeb963826016ca1c8519518",
+    "28c57008351d171d012de5ae4d1985fa7271ec6471749e6c0b3c26f815fdb9d8",
+    "166fec85611f16cef4b696de813d071a3f10c000acb839e14ec52317bd2e2e9e",
+    "206800b59c9c720c2e2bed8a29da13f7f581f35c0fd6109086ba8025b74669f1",
+    "0f0da7e37531ea28610f8b471bf7778da9b0d8e446fded2cc4e877bdb989e66f",
+    "042ea058eebebb860a9e9fc7a074f0b962b5c0fa1e6ff02d276a8bda7d929a6c",
+    "17072b2ed3bb8d759a5325f477629386cb6fc6ecb801bd76983a6b86abffe078",
+    "168ada6cd130dd52017bb54bfa19377aadfe3bf05d18f41b77809f7f60d4af9e",
+    "2ede61482cb8d95b6b56d69709667144825f9bd850d74904ed9e5d3b43b91a9c",
+    "30309e1bd4e882958a9384af43c59eecb19a696fffedf5ec680b41fee3b23568",
+    "2cff5e10c3bdbe0cf586b392f63d205d5056ffca26ed81d0892c996d7c54ad87",
+    "156a25d04111f2f4f69e810af77a85f74b9335e651dfa07e906ab499449202b8",
+    "2b17b370ca5e6bd825304311b18cdc0ac56b85bcaca940bd8bfc7b883fcd5c35",
+    "08cc46dc025116a401ded44447a0fe9034c6cc7cbf5921f7831f06eb645921ef",
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+];
+
+#[test]
+fn empty_input() {
+    assert!(pairing_check(&[]));
+}
+
+#[test]
+fn generators() {
+    let (p, q) = (G1Affine::GENERATOR, G2Affine::GENERATOR);
+    assert!(!pairing_check(&[(p, q)]));
+    assert!(pairing_check(&[(p, q), (-p, q)]));
+    assert!(pairing_check(&[(p, q), (p, -q)]));
+    assert!(!pairing_check(&[(p, q), (p, q)]));
+}
+
+#[test]
+fn points_at_infinity() {
+    let (p, q) = (G1Affine::GENERATOR, G2Affine::GENERATOR);
+    assert!(pairing_check(&[(G1Affine::IDENTITY, q)]));
+    assert!(pairing_check(&[(p, G2Affine::IDENTITY)]));
+    assert!(!pairing_check(&[(p, q), (G1Affine::IDENTITY, q)]));
+}
+
+#[test]
+fn eip197_vectors() {
+    assert!(pairing_check(&decode(MATCH)));
+    assert!(!pairing_check(&decode(MISMATCH)));
+    assert!(pairing_check(&decode(THREE_POINT)));
+}
+
+#[test]
+fn rejects_invalid_g2_points() {
+    let (x, y) = G2Affine::GENERATOR.to_xy().unwrap();
+    assert_eq!(G2Affine::new(x, y), Some(G2Affine::GENERATOR));
+    assert_eq!(G2Affine::new(x, y + Fq2::ONE), None);
+
+    // On the twist, but outside the prime-order subgroup.
+    let x = Fq2::ONE;
+    let y = fq2(
+        "0d1271953ed9ea0836846e70a1934187998c7f790cb4d7511b7f8da82de048a4",
+        "2869111d5381f072f8e2728fdb825a51aadd70e52c9830e9ab4b871c0531f1bb",
+    );
+    assert_eq!(G2Affine::new(x, y), None);
+}
@@ -0,0 +1,72 @@
+//! The secp256k1 curve syscall wrappers.
+
+use sp1_intrinsics::secp256k1::{
+    syscall_secp256k1_add, syscall_secp256k1_decompress, syscall_secp256k1_double,
+};
+
+/// Little-endian limbs of a big-endian hex integer.
+fn limbs(hex: &str) -> [u32; 8] {
+    core::array::from_fn(|i| u32::from_str_radix(&hex[56 - 8 * i..64 - 8 * i], 16).unwrap())
+}
+
+fn point(x: &str, y: &str) -> [u32; 16] {
+    let mut point = [0; 16];
+    point[..8].copy_from_slice(&limbs(x));
+    point[8..].copy_from_slice(&limbs(y));
+    point
+}
+
+fn g() -> [u32; 16] {
+    point(
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    )
+}
+
+fn g2() -> [u32; 16] {
+    point(
+        "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+        "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+    )
+}
+
+fn g3() -> [u32; 16] {
+    point(
+        "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        "388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+    )
+}
+
+#[test]
+fn add_and_double() {
+    let mut p = g();
+    unsafe { syscall_secp256k1_double(&mut p) };
+    assert_eq!(p, g2());
+
+    unsafe { syscall_secp256k1_add(&mut p, &g()) };
+    assert_eq!(p, g3());
+}
+
+#[test]
+fn decompress() {
+    #[repr(align(4))]
+    struct Bytes([u8; 64]);
+
+    let g = g();
+    let mut point = Bytes([0; 64]);
+    for (chunk, limb) in point.0[32..].chunks_exact_mut(4).zip(&g[..8]) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+
+    // The y coordinate of the generator is even.
+    unsafe { syscall_secp256k1_decompress(&mut point.0, false) };
+    let y: Vec<u32> = point.0[..32]
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    assert_eq!(y, g[8..]);
+
+    unsafe { syscall_secp256k1_decompress(&mut point.0, true) };
+    assert_eq!(point.0[0] & 1, 1);
+    assert_ne!(point.0[..4], g[8].to_le_bytes());
+}
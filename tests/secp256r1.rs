@@ -0,0 +1,115 @@
+//! The secp256r1 curve syscall wrappers and ECDSA verification.
+#![cfg(feature = "sp1-v3")]
+
+use sp1_intrinsics::secp256r1::{
+    ecdsa_verify, syscall_secp256r1_add, syscall_secp256r1_decompress, syscall_secp256r1_double,
+};
+
+/// Little-endian limbs of a big-endian hex integer.
+fn limbs(hex: &str) -> [u32; 8] {
+    core::array::from_fn(|i| u32::from_str_radix(&hex[56 - 8 * i..64 - 8 * i], 16).unwrap())
+}
+
+fn point(x: &str, y: &str) -> [u32; 16] {
+    let mut point = [0; 16];
+    point[..8].copy_from_slice(&limbs(x));
+    point[8..].copy_from_slice(&limbs(y));
+    point
+}
+
+fn bytes<const N: usize>(hex: &str) -> [u8; N] {
+    core::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+}
+
+fn g() -> [u32; 16] {
+    point(
+        "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    )
+}
+
+#[test]
+fn add_and_double() {
+    let mut p = g();
+    unsafe { syscall_secp256r1_double(&mut p) };
+    assert_eq!(
+        p,
+        point(
+            "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+            "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+        )
+    );
+
+    unsafe { syscall_secp256r1_add(&mut p, &g()) };
+    assert_eq!(
+        p,
+        point(
+            "5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c",
+            "8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032",
+        )
+    );
+}
+
+#[test]
+fn decompress() {
+    #[repr(align(4))]
+    struct Bytes([u8; 64]);
+
+    let g = g();
+    let mut point = Bytes([0; 64]);
+    for (chunk, limb) in point.0[32..].chunks_exact_mut(4).zip(&g[..8]) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+
+    // The y coordinate of the generator is odd.
+    unsafe { syscall_secp256r1_decompress(&mut point.0, true) };
+    let y: Vec<u32> = point.0[..32]
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    assert_eq!(y, g[8..]);
+}
+
+/// RFC 6979, A.2.5: P-256 with SHA-256, message `sample`.
+const PUBKEY: &str = "60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6\
+                      7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+const HASH: &str = "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf";
+const SIG: &str = "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+                   f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8";
+
+#[test]
+fn verifies_rfc6979_signature() {
+    assert!(ecdsa_verify(&bytes(PUBKEY), &bytes(HASH), &bytes(SIG)));
+
+    // (r, n - s) is also valid.
+    let mut sig: [u8; 64] = bytes(SIG);
+    sig[32..].copy_from_slice(&bytes::<32>(
+        "0834e36ad29a83bf2bc9385e491d6099c8fdf9d1ed67aa7ea5f51f93782857a9",
+    ));
+    assert!(ecdsa_verify(&bytes(PUBKEY), &bytes(HASH), &sig));
+}
+
+#[test]
+fn rejects_wrong_message_or_key() {
+    let mut hash: [u8; 32] = bytes(HASH);
+    hash[0] ^= 1;
+    assert!(!ecdsa_verify(&bytes(PUBKEY), &hash, &bytes(SIG)));
+
+    let mut pubkey: [u8; 64] = bytes(PUBKEY);
+    pubkey[63] ^= 1;
+    assert!(!ecdsa_verify(&pubkey, &bytes(HASH), &bytes(SIG)));
+}
+
+#[test]
+fn rejects_out_of_range_scalars() {
+    let mut sig: [u8; 64] = bytes(SIG);
+    sig[32..].fill(0);
+    assert!(!ecdsa_verify(&bytes(PUBKEY), &bytes(HASH), &sig));
+
+    // r = n
+    let mut sig: [u8; 64] = bytes(SIG);
+    sig[..32].copy_from_slice(&bytes::<32>(
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    ));
+    assert!(!ecdsa_verify(&bytes(PUBKEY), &bytes(HASH), &sig));
+}
@@ -0,0 +1,56 @@
+//! The SHA-256 hasher driven by the extend and compress syscalls.
+
+use sp1_intrinsics::sha256::Sha256;
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn digest(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
+#[test]
+fn known_answers() {
+    let vectors: [(&[u8], &str); 3] = [
+        (
+            b"",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ];
+    for (message, expected) in vectors {
+        assert_eq!(digest(message).to_vec(), hex(expected));
+    }
+}
+
+#[test]
+fn streaming() {
+    let message: Vec<u8> = (0..3).flat_map(|_| 0..=255u8).collect();
+    let expected = hex("f3a25aa93aa2fbba28d79260535bbd6a5eb0fc1c24a8b0f04e12b484c1dfe363");
+    assert_eq!(digest(&message).to_vec(), expected);
+
+    for chunk_len in [1, 7, 55, 56, 63, 64, 65, 200] {
+        let mut hasher = Sha256::new();
+        for chunk in message.chunks(chunk_len) {
+            hasher.update(chunk);
+        }
+        assert_eq!(
+            hasher.finalize().to_vec(),
+            expected,
+            "chunk length {chunk_len}"
+        );
+    }
+}
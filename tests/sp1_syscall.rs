@@ -0,0 +1,55 @@
+//! Syscall wrappers declared with `#[sp1_syscall]`.
+#![cfg(feature = "macros")]
+
+use sp1_intrinsics::bn254::{self, Fr};
+use sp1_intrinsics::sp1_syscall;
+
+/// Perform in-place scalar multiplication `p *= q`.
+#[sp1_syscall(id = bn254::BN254_SCALAR_MUL, args(p: mut Fr, q: Fr))]
+pub fn bn254_scalar_mul();
+
+/// Perform in-place 64-bit wrapping addition `x += y`.
+#[sp1_syscall(id = 0x00_01_01_F7, args(x: mut u64, y: u64), host = host_add)]
+fn wrapping_add();
+
+/// Perform in-place negation of the limbs of `x`.
+#[sp1_syscall(id = 0x00_00_01_F8, args(x: mut [u32; 2]), host = host_not)]
+fn not();
+
+unsafe fn host_add(x: *mut u64, y: *const u64) {
+    unsafe { *x = (*x).wrapping_add(*y) }
+}
+
+unsafe fn host_not(x: *mut [u32; 2]) {
+    unsafe { *x = (*x).map(|limb| !limb) }
+}
+
+#[test]
+fn builtin_syscall() {
+    assert_eq!(BN254_SCALAR_MUL, bn254::BN254_SCALAR_MUL);
+
+    let (a, b) = (Fr::from(0x1234_5678), -Fr::from(3));
+    let mut p = a;
+    bn254_scalar_mul(&mut p, &b);
+    assert_eq!(p, a * b);
+
+    let mut p = a;
+    unsafe { syscall_bn254_scalar_mul(&mut p, &b) };
+    assert_eq!(p, a * b);
+}
+
+#[test]
+fn host_implementation() {
+    assert_eq!(WRAPPING_ADD, 0x00_01_01_F7);
+
+    let mut x = u64::MAX;
+    wrapping_add(&mut x, &2);
+    assert_eq!(x, 1);
+
+    let mut x = [0, u32::MAX];
+    not(&mut x);
+    assert_eq!(x, [u32::MAX, 0]);
+    unsafe { syscall_not(&mut x) };
+    assert_eq!(x, [0, u32::MAX]);
+    assert_eq!(NOT, 0x00_00_01_F8);
+}
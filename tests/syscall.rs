@@ -0,0 +1,249 @@
+//! Decoding of the packed syscall codes, and their consistency with the pinned SP1 tables.
+
+#[cfg(feature = "sp1-v3")]
+use sp1_intrinsics::secp256r1;
+use sp1_intrinsics::{
+    bls12_381, bn254, ed25519, keccak, memory, secp256k1, sha256, uint256, SyscallCode,
+};
+
+#[test]
+fn round_trip() {
+    for &code in SyscallCode::ALL {
+        assert_eq!(SyscallCode::from_u32(code.as_u32()), Some(code));
+        assert_eq!(u32::from(code), code.as_u32());
+        // Byte 3 is unused.
+        assert_eq!(code.as_u32() >> 24, 0, "{code:?}");
+    }
+    assert_eq!(SyscallCode::from_u32(0), None);
+    assert_eq!(SyscallCode::from_u32(0x00_01_01_7F), None);
+}
+
+#[test]
+fn fields() {
+    let sha_extend = SyscallCode::ShaExtend;
+    assert_eq!(sha_extend.syscall_id(), 0x05);
+    assert!(sha_extend.has_precompile_table());
+    assert_eq!(sha_extend.extra_cycles(), 48);
+
+    let scalar_mul = SyscallCode::Bn254ScalarMul;
+    assert_eq!(scalar_mul.syscall_id(), 0x80);
+    assert!(scalar_mul.has_precompile_table());
+    assert_eq!(scalar_mul.extra_cycles(), 1);
+
+    assert_eq!(SyscallCode::Bn254Double.extra_cycles(), 0);
+}
+
+#[test]
+fn constants() {
+    assert_eq!(
+        bn254::BN254_SCALAR_MUL,
+        SyscallCode::Bn254ScalarMul.as_u32()
+    );
+    assert_eq!(bn254::BN254_SCALAR_MUL, 0x00_01_01_80);
+    assert_eq!(memory::SYSCALL_ID_MEMCPY_32, 0x00_01_01_90);
+    assert_eq!(sha256::SHA_EXTEND, 0x00_30_01_05);
+}
+
+/// The pinned syscall tables of the supported SP1 releases, oldest first.
+const TABLES: [(&str, &str); 3] = [
+    ("sp1-v1", include_str!("../syscalls/sp1-v1.toml")),
+    ("sp1-v2", include_str!("../syscalls/sp1-v2.toml")),
+    ("sp1-v3", include_str!("../syscalls/sp1-v3.toml")),
+];
+
+/// The table of the SP1 release selected by the features of the build.
+fn selected_table() -> &'static str {
+    let version = if cfg!(feature = "sp1-v1") {
+        "sp1-v1"
+    } else if cfg!(feature = "sp1-v2") {
+        "sp1-v2"
+    } else {
+        "sp1-v3"
+    };
+    TABLES.iter().find(|(v, _)| *v == version).unwrap().1
+}
+
+/// Parse the `NAME = 0x..` entries of a syscall table.
+fn parse(table: &str) -> Vec<(&str, u32)> {
+    table
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with(['#', '[']))
+        .map(|line| {
+            let (name, code) = line.split_once(" = ").expect("malformed entry");
+            let code = code.strip_prefix("0x").expect("code is not hexadecimal");
+            let code = u32::from_str_radix(&code.replace('_', ""), 16).unwrap();
+            (name, code)
+        })
+        .collect()
+}
+
+fn lookup(table: &[(&str, u32)], name: &str) -> Option<u32> {
+    table
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|&(_, code)| code)
+}
+
+#[test]
+fn pinned_tables_are_consistent() {
+    let mut previous: Vec<(&str, u32)> = Vec::new();
+    for (version, table) in TABLES {
+        let table = parse(table);
+        for (i, &(name, code)) in table.iter().enumerate() {
+            for &(other, other_code) in &table[i + 1..] {
+                assert_ne!(name, other, "{version}: {name} listed twice");
+                assert_ne!(
+                    code.to_le_bytes()[0],
+                    other_code.to_le_bytes()[0],
+                    "{version}: {name} and {other} share a syscall number"
+                );
+            }
+        }
+        // Releases only add syscalls, and keep the codes of the others.
+        for &(name, code) in &previous {
+            assert_eq!(lookup(&table, name), Some(code), "{version}: {name}");
+        }
+        previous = table;
+    }
+}
+
+#[test]
+fn matches_pinned_table() {
+    let table = parse(selected_table());
+    for &code in SyscallCode::ALL {
+        assert_eq!(lookup(&table, code.name()), Some(code.as_u32()), "{code:?}");
+    }
+
+    let constants = [
+        ("BLS12381_ADD", bls12_381::BLS12381_ADD),
+        ("BLS12381_DOUBLE", bls12_381::BLS12381_DOUBLE),
+        ("BLS12381_DECOMPRESS", bls12_381::BLS12381_DECOMPRESS),
+        ("BN254_SCALAR_MUL", bn254::BN254_SCALAR_MUL),
+        ("BN254_SCALAR_MAC", bn254::BN254_SCALAR_MAC),
+        ("BN254_ADD", bn254::BN254_ADD),
+        ("BN254_DOUBLE", bn254::BN254_DOUBLE),
+        #[cfg(not(feature = "sp1-v1"))]
+        ("BN254_FP_ADD", bn254::BN254_FP_ADD),
+        #[cfg(not(feature = "sp1-v1"))]
+        ("BN254_FP_SUB", bn254::BN254_FP_SUB),
+        #[cfg(not(feature = "sp1-v1"))]
+        ("BN254_FP_MUL", bn254::BN254_FP_MUL),
+        #[cfg(not(feature = "sp1-v1"))]
+        ("BN254_FP2_ADD", bn254::BN254_FP2_ADD),
+        #[cfg(not(feature = "sp1-v1"))]
+        ("BN254_FP2_SUB", bn254::BN254_FP2_SUB),
+        #[cfg(not(feature = "sp1-v1"))]
+        ("BN254_FP2_MUL", bn254::BN254_FP2_MUL),
+        ("ED_ADD", ed25519::ED_ADD),
+        ("ED_DECOMPRESS", ed25519::ED_DECOMPRESS),
+        ("KECCAK_PERMUTE", keccak::KECCAK_PERMUTE),
+        ("MEMCPY_32", memory::SYSCALL_ID_MEMCPY_32),
+        ("MEMCPY_64", memory::SYSCALL_ID_MEMCPY_64),
+        ("SECP256K1_ADD", secp256k1::SECP256K1_ADD),
+        ("SECP256K1_DOUBLE", secp256k1::SECP256K1_DOUBLE),
+        ("SECP256K1_DECOMPRESS", secp256k1::SECP256K1_DECOMPRESS),
+        #[cfg(feature = "sp1-v3")]
+        ("SECP256R1_ADD", secp256r1::SECP256R1_ADD),
+        #[cfg(feature = "sp1-v3")]
+        ("SECP256R1_DOUBLE", secp256r1::SECP256R1_DOUBLE),
+        #[cfg(feature = "sp1-v3")]
+        ("SECP256R1_DECOMPRESS", secp256r1::SECP256R1_DECOMPRESS),
+        ("SHA_EXTEND", sha256::SHA_EXTEND),
+        ("SHA_COMPRESS", sha256::SHA_COMPRESS),
+        ("UINT256_MUL", uint256::UINT256_MULMOD),
+    ];
+    for (name, constant) in constants {
+        assert_eq!(lookup(&table, name), Some(constant), "{name}");
+    }
+}
+
+/// Stand-ins for the wrappers of syscalls missing from the selected release.
+///
+/// Each test glob-imports a stand-in next to the module of the crate that would define the
+/// real item. A name provided by both globs is ambiguous and does not compile, so the tests
+/// only build if the crate lacks the item.
+mod missing {
+    pub mod bn254_muladd {
+        pub const BN254_MULADD: () = ();
+        pub fn syscall_bn254_muladd() {}
+        pub fn syscall_bn254_muladd_entrypoint() {}
+    }
+
+    #[cfg(feature = "sp1-v1")]
+    pub mod bn254 {
+        pub const BN254_FP_ADD: () = ();
+        pub const BN254_FP_SUB: () = ();
+        pub const BN254_FP_MUL: () = ();
+        pub const BN254_FP2_ADD: () = ();
+        pub const BN254_FP2_SUB: () = ();
+        pub const BN254_FP2_MUL: () = ();
+        pub fn syscall_bn254_fp_addmod() {}
+        pub fn syscall_bn254_fp_submod() {}
+        pub fn syscall_bn254_fp_mulmod() {}
+        pub fn syscall_bn254_fp2_addmod() {}
+        pub fn syscall_bn254_fp2_submod() {}
+        pub fn syscall_bn254_fp2_mulmod() {}
+    }
+
+    #[cfg(not(feature = "sp1-v3"))]
+    pub mod secp256r1 {
+        pub const MISSING: () = ();
+    }
+}
+
+#[test]
+#[cfg(feature = "sp1-v1")]
+fn no_bn254_base_field_wrappers() {
+    use missing::bn254::*;
+    #[allow(unused_imports)]
+    use sp1_intrinsics::bn254::*;
+
+    let () = BN254_FP_ADD;
+    let () = BN254_FP_SUB;
+    let () = BN254_FP_MUL;
+    let () = BN254_FP2_ADD;
+    let () = BN254_FP2_SUB;
+    let () = BN254_FP2_MUL;
+    syscall_bn254_fp_addmod();
+    syscall_bn254_fp_submod();
+    syscall_bn254_fp_mulmod();
+    syscall_bn254_fp2_addmod();
+    syscall_bn254_fp2_submod();
+    syscall_bn254_fp2_mulmod();
+
+    assert!(SyscallCode::ALL
+        .iter()
+        .all(|code| !code.name().starts_with("BN254_FP")));
+}
+
+#[test]
+#[cfg(not(feature = "sp1-v3"))]
+fn no_secp256r1_wrappers() {
+    use missing::*;
+    use sp1_intrinsics::*;
+
+    let () = secp256r1::MISSING;
+
+    assert!(SyscallCode::ALL
+        .iter()
+        .all(|code| !code.name().starts_with("SECP256R1")));
+}
+
+#[test]
+fn no_bn254_muladd_wrappers() {
+    use missing::bn254_muladd::*;
+    #[allow(unused_imports)]
+    use sp1_intrinsics::bn254::*;
+
+    let () = BN254_MULADD;
+    syscall_bn254_muladd();
+    syscall_bn254_muladd_entrypoint();
+
+    // The code the wrappers issued, whose syscall number is the one of `BLS12381_DOUBLE`.
+    let code = 0x00_01_01_1F;
+    for (version, table) in TABLES {
+        let table = parse(table);
+        assert!(table.iter().all(|&(_, c)| c != code), "{version}");
+    }
+    assert_eq!(SyscallCode::from_u32(code), None);
+}
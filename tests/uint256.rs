@@ -0,0 +1,134 @@
+//! The 256-bit modular multiplication syscall and the `U256` integer.
+
+use sp1_intrinsics::bn254::Fr;
+use sp1_intrinsics::uint256::{uint256_mulmod, U256};
+
+/// The bn254 base field modulus `q`, a 254-bit prime.
+const Q: U256 = U256::from_limbs([
+    0xd87cfd47, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+]);
+
+#[test]
+fn syscall() {
+    let mut x = [7, 0, 0, 0, 0, 0, 0, 0];
+    let mut y_and_modulus = [0u32; 16];
+    y_and_modulus[0] = 9;
+    y_and_modulus[8] = 10;
+    uint256_mulmod(&mut x, &y_and_modulus);
+    assert_eq!(x, [3, 0, 0, 0, 0, 0, 0, 0]);
+
+    // A zero modulus wraps at 2^256, and the inputs do not have to be reduced.
+    let mut x = [u32::MAX; 8];
+    let mut y_and_modulus = [0u32; 16];
+    y_and_modulus[..8].fill(u32::MAX);
+    uint256_mulmod(&mut x, &y_and_modulus);
+    assert_eq!(x, [1, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn mul_mod() {
+    let cases: [(u64, u64, u64); 4] = [
+        (0, 12345, 97),
+        (u64::MAX, u64::MAX, 0xffff_fffb),
+        (0x1234_5678_9abc_def0, 0x0fed_cba9_8765_4321, 1 << 40),
+        (5, 7, 1),
+    ];
+    for (a, b, m) in cases {
+        let expected = (a as u128 * b as u128 % m as u128) as u64;
+        let result = U256::from(a).mul_mod(&U256::from(b), &U256::from(m));
+        assert_eq!(result, U256::from(expected), "{a} * {b} mod {m}");
+    }
+
+    // Agrees with the scalar field syscalls modulo r.
+    let mut r = U256::from_le_bytes(&(-Fr::ONE).to_bytes()).to_limbs();
+    r[0] += 1;
+    let (a, b) = (Fr::from(u64::MAX), -Fr::from(3));
+    let (x, y) = (
+        U256::from_le_bytes(&a.to_bytes()),
+        U256::from_le_bytes(&b.to_bytes()),
+    );
+    let product = x.mul_mod(&y, &U256::from_limbs(r));
+    assert_eq!(product.to_le_bytes(), (a * b).to_bytes());
+
+    let mut max_minus_one = U256::MAX.to_limbs();
+    max_minus_one[0] -= 1;
+    let doubled = U256::MAX.mul_mod(&U256::from(2), &U256::ZERO);
+    assert_eq!(doubled, U256::from_limbs(max_minus_one));
+}
+
+#[test]
+fn pow_mod() {
+    assert_eq!(
+        U256::from(4).pow_mod(&U256::from(13), &U256::from(497)),
+        U256::from(445)
+    );
+    assert_eq!(
+        U256::from(3).pow_mod(&U256::ZERO, &U256::from(7)),
+        U256::ONE
+    );
+    assert_eq!(U256::from(3).pow_mod(&U256::ZERO, &U256::ONE), U256::ZERO);
+
+    // A zero modulus wraps at 2^256.
+    let two = U256::from(2);
+    let mut top_bit = [0; 8];
+    top_bit[7] = 1 << 31;
+    assert_eq!(
+        two.pow_mod(&U256::from(255), &U256::ZERO),
+        U256::from_limbs(top_bit)
+    );
+    assert_eq!(two.pow_mod(&U256::from(256), &U256::ZERO), U256::ZERO);
+
+    // Fermat's little theorem modulo q.
+    let mut q_minus_one = Q.to_limbs();
+    q_minus_one[0] -= 1;
+    let a = U256::from_be_bytes(&[0x5a; 32]);
+    assert_eq!(a.pow_mod(&U256::from_limbs(q_minus_one), &Q), U256::ONE);
+}
+
+#[test]
+fn inv_mod() {
+    assert_eq!(
+        U256::from(7).inv_mod(&U256::from(100)),
+        Some(U256::from(43))
+    );
+    assert_eq!(
+        U256::from(107).inv_mod(&U256::from(100)),
+        Some(U256::from(43))
+    );
+    assert_eq!(U256::from(6).inv_mod(&U256::from(9)), None);
+    assert_eq!(U256::ZERO.inv_mod(&U256::from(9)), None);
+    assert_eq!(U256::from(5).inv_mod(&U256::ONE), Some(U256::ZERO));
+
+    // Modulo 2^256, exactly the odd integers are invertible.
+    for a in [
+        U256::ONE,
+        U256::from(3),
+        U256::MAX,
+        U256::from_be_bytes(&[0x5b; 32]),
+    ] {
+        let inv = a.inv_mod(&U256::ZERO).unwrap();
+        assert_eq!(a.mul_mod(&inv, &U256::ZERO), U256::ONE);
+    }
+    assert_eq!(U256::from(2).inv_mod(&U256::ZERO), None);
+
+    let a = U256::from_be_bytes(&[0xa5; 32]);
+    let inv = a.inv_mod(&Q).unwrap();
+    assert_eq!(a.mul_mod(&inv, &Q), U256::ONE);
+    let mut q_minus_two = Q.to_limbs();
+    q_minus_two[0] -= 2;
+    assert_eq!(inv, a.pow_mod(&U256::from_limbs(q_minus_two), &Q));
+}
+
+#[test]
+fn bytes_and_order() {
+    let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let a = U256::from_be_bytes(&bytes);
+    assert_eq!(a.to_be_bytes(), bytes);
+    assert_eq!(U256::from_le_bytes(&a.to_le_bytes()), a);
+    assert_eq!(a.to_limbs()[0], 0x1c1d1e1f);
+
+    assert!(U256::from(u64::MAX) < U256::from_limbs([0, 0, 1, 0, 0, 0, 0, 0]));
+    assert!(U256::MAX > Q);
+    assert_eq!(U256::ZERO.bits(), 0);
+    assert_eq!(Q.bits(), 254);
+}